@@ -62,6 +62,7 @@ fn retrieve(
         Span::detached(),
         EvalMode::Code,
         Scope::default(),
+        world.library().limits,
     )
     .map_err(|errors| {
         let mut message = EcoString::from("failed to evaluate selector");
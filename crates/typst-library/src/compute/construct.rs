@@ -3,14 +3,14 @@ use std::str::FromStr;
 
 use time::{Month, PrimitiveDateTime};
 
-use typst::eval::{Bytes, Datetime, Module, Reflect, Regex};
+use typst::eval::{float_to_int, Bytes, Datetime, Module, Reflect, Regex};
 
 use crate::prelude::*;
 
 /// Converts a value to an integer.
 ///
 /// - Booleans are converted to `0` or `1`.
-/// - Floats are floored to the next 64-bit integer.
+/// - Floats are truncated towards zero, so `{int(-2.7)}` is `{-2}`.
 /// - Strings are parsed in base 10.
 ///
 /// ## Example { #example }
@@ -37,7 +37,7 @@ pub struct ToInt(i64);
 cast! {
     ToInt,
     v: bool => Self(v as i64),
-    v: f64 => Self(v as i64),
+    v: f64 => Self(float_to_int(v)?),
     v: EcoString => Self(v.parse().map_err(|_| eco_format!("invalid integer: {}", v))?),
     v: i64 => Self(v),
 }
@@ -594,7 +594,7 @@ fn int_to_base(mut n: i64, base: i64) -> EcoString {
 /// ## Example
 /// ```example
 /// #str.to-unicode("a") \
-/// #"a\u{0300}".codepoints().map(str.to-unicode)
+/// #"abc".chars().map(str.to-unicode)
 /// ```
 ///
 /// Display: String To Unicode
@@ -719,7 +719,9 @@ cast! {
 ///
 /// Inserting a label into content attaches it to the closest previous element
 /// that is not a space. Then, the element can be [referenced]($func/ref) and
-/// styled through the label.
+/// styled through the label. Labels never reach beyond the content block they
+/// are in. If there is no element to attach to, the label has no effect and
+/// Typst warns about it.
 ///
 /// ## Example { #example }
 /// ```example
@@ -749,6 +751,8 @@ pub fn label(
 /// Note that this function is only intended for conversion of a collection-like
 /// value to an array, not for creation of an array from individual items. Use
 /// the array syntax `(1, 2, 3)` (or `(1,)` for a single-element array) instead.
+/// A variadic form like `{array(1, 2, 3)}` is deliberately not supported as
+/// `{array((1, 2))}` would then be ambiguous.
 ///
 /// ```example
 /// #let hi = "Hello 😃"
@@ -774,6 +778,90 @@ cast! {
     v: Array => Self(v),
 }
 
+/// Converts a value to a dictionary.
+///
+/// Modules and arrays of key-value pairs can be converted. For a module, the
+/// resulting dictionary maps the name of each definition in the module to its
+/// value. An array must consist of arrays with exactly two items, a key and a
+/// value, like those returned by [`pairs`]($type/dictionary.pairs). Keys that
+/// aren't strings are converted like with the [`str`]($func/str) function. If a
+/// key occurs multiple times, the last value wins.
+///
+/// Without a value to convert, the named arguments form the dictionary, so
+/// `{dictionary(a: 1, b: 2)}` is the same as `{(a: 1, b: 2)}`.
+///
+/// ```example
+/// #let names = dictionary(calc).keys()
+/// #names.slice(0, 4)
+///
+/// #let prices = (apple: 1, pear: 2)
+/// #dictionary(prices.pairs().map(
+///   ((k, v)) => (upper(k), v * 2)
+/// ))
+/// ```
+///
+/// Combined with [`zip`]($type/array.zip), this turns parallel arrays of keys
+/// and values into a dictionary, for example a header row and a data row of a
+/// CSV file. Surplus items of the longer array are ignored.
+///
+/// ```example
+/// #let header = ("name", "age", "city")
+/// #let row = ("Ada", 36)
+/// #dictionary(header.zip(row))
+/// ```
+///
+/// Display: Dictionary
+/// Category: construct
+#[func]
+pub fn dictionary(
+    /// The value that should be converted to a dictionary.
+    #[external]
+    #[default(ToDict(Dict::new()))]
+    value: ToDict,
+    /// The arguments.
+    args: Args,
+) -> SourceResult<Dict> {
+    let mut args = args;
+    let dict = match args.eat::<ToDict>()? {
+        Some(value) => value.0,
+        None => {
+            let named = args.to_named();
+            args.items.retain(|arg| arg.name.is_none());
+            named
+        }
+    };
+    args.finish()?;
+    Ok(dict)
+}
+
+/// A value that can be cast to a dictionary.
+pub struct ToDict(Dict);
+
+cast! {
+    ToDict,
+    self => self.0.into_value(),
+    v: Module => Self(
+        v.scope().iter().map(|(k, v)| (k.clone().into(), v.clone())).collect()
+    ),
+    v: Dict => Self(v),
+    v: Array => Self(v.into_iter()
+        .map(|pair| {
+            let pair = pair.cast::<Array>()?;
+            match pair.as_slice() {
+                [key, value] => {
+                    let key = match key.clone().cast::<ToStr>()? {
+                        ToStr::Str(key) => key,
+                        ToStr::Int(key) => format_str!("{}", key),
+                    };
+                    Ok((key, value.clone()))
+                }
+                _ => bail!("pair must have exactly two items, found {}", pair.len()),
+            }
+        })
+        .collect::<StrResult<Dict>>()?
+    ),
+}
+
 /// Creates an array consisting of consecutive integers.
 ///
 /// If you pass just one positional parameter, it is interpreted as the `end` of
@@ -89,7 +89,7 @@ impl From<Readable> for Bytes {
 /// The CSV file will be read and parsed into a 2-dimensional array of strings:
 /// Each row in the CSV file will be represented as an array of strings, and all
 /// rows will be collected into a single array. Header rows will not be
-/// stripped.
+/// stripped unless `header` is enabled.
 ///
 /// ## Example { #example }
 /// ```example
@@ -105,6 +105,10 @@ impl From<Readable> for Bytes {
 /// Display: CSV
 /// Category: data-loading
 #[func]
+#[scope(
+    scope.define("decode", csv_decode_func());
+    scope
+)]
 pub fn csv(
     /// Path to a CSV file.
     path: Spanned<EcoString>,
@@ -113,12 +117,64 @@ pub fn csv(
     #[named]
     #[default]
     delimiter: Delimiter,
+    /// Whether the first row contains the column names. If enabled, each
+    /// following row is returned as a dictionary from column names to
+    /// fields.
+    #[named]
+    #[default(false)]
+    header: bool,
     /// The virtual machine.
     vm: &mut Vm,
 ) -> SourceResult<Array> {
     let Spanned { v: path, span } = path;
     let id = vm.location().join(&path).at(span)?;
     let data = vm.world().file(id).at(span)?;
+    decode_csv(Spanned::new(Readable::Bytes(data), span), delimiter, header, "csv file")
+}
+
+/// Reads structured data from a CSV string or bytes.
+///
+/// The data is parsed in the same way as by the [`csv`]($func/csv) function.
+/// Quoted fields may contain delimiters and line breaks.
+///
+/// ## Example { #example }
+/// ```example
+/// #let rows = csv.decode("name,age\nAnna,30\nBen,25", header: true)
+/// #for row in rows [
+///   #row.name is #row.age years old. \
+/// ]
+/// ```
+///
+/// Display: Decode CSV
+/// Category: data-loading
+#[func]
+pub fn csv_decode(
+    /// CSV data.
+    data: Spanned<Readable>,
+    /// The delimiter that separates columns in the CSV data.
+    /// Must be a single ASCII character.
+    #[named]
+    #[default]
+    delimiter: Delimiter,
+    /// Whether the first row contains the column names. If enabled, each
+    /// following row is returned as a dictionary from column names to
+    /// fields.
+    #[named]
+    #[default(false)]
+    header: bool,
+) -> SourceResult<Array> {
+    decode_csv(data, delimiter, header, "csv")
+}
+
+/// Decode CSV data, calling it `format` in error messages.
+fn decode_csv(
+    data: Spanned<Readable>,
+    delimiter: Delimiter,
+    header: bool,
+    format: &str,
+) -> SourceResult<Array> {
+    let Spanned { v: data, span } = data;
+    let data: Bytes = data.into();
 
     let mut builder = csv::ReaderBuilder::new();
     builder.has_headers(false);
@@ -126,15 +182,29 @@ pub fn csv(
 
     let mut reader = builder.from_reader(data.as_slice());
     let mut array = Array::new();
+    let mut names: Option<Vec<Str>> = None;
 
     for (line, result) in reader.records().enumerate() {
         // Original solution use line from error, but that is incorrect with
         // `has_headers` set to `false`. See issue:
         // https://github.com/BurntSushi/rust-csv/issues/184
         let line = line + 1; // Counting lines from 1
-        let row = result.map_err(|err| format_csv_error(err, line)).at(span)?;
-        let sub = row.into_iter().map(|field| field.into_value()).collect();
-        array.push(Value::Array(sub))
+        let row = result.map_err(|err| format_csv_error(err, line, format)).at(span)?;
+        if header {
+            match &names {
+                None => names = Some(row.iter().map(Str::from).collect()),
+                Some(names) => array.push(Value::Dict(
+                    names
+                        .iter()
+                        .cloned()
+                        .zip(row.iter().map(|field| field.into_value()))
+                        .collect(),
+                )),
+            }
+        } else {
+            let sub = row.into_iter().map(|field| field.into_value()).collect();
+            array.push(Value::Array(sub))
+        }
     }
 
     Ok(array)
@@ -168,15 +238,15 @@ cast! {
 }
 
 /// Format the user-facing CSV error message.
-fn format_csv_error(error: csv::Error, line: usize) -> EcoString {
+fn format_csv_error(error: csv::Error, line: usize, format: &str) -> EcoString {
     match error.kind() {
         csv::ErrorKind::Utf8 { .. } => "file is not valid utf-8".into(),
         csv::ErrorKind::UnequalLengths { expected_len, len, .. } => {
             eco_format!(
-                "failed to parse csv file: found {len} instead of {expected_len} fields in line {line}"
+                "failed to parse {format}: found {len} instead of {expected_len} fields in line {line}"
             )
         }
-        _ => "failed to parse csv file".into(),
+        _ => eco_format!("failed to parse {format}"),
     }
 }
 
@@ -221,6 +291,11 @@ fn format_csv_error(error: csv::Error, line: usize) -> EcoString {
 /// Display: JSON
 /// Category: data-loading
 #[func]
+#[scope(
+    scope.define("decode", json_decode_func());
+    scope.define("encode", json_encode_func());
+    scope
+)]
 pub fn json(
     /// Path to a JSON file.
     path: Spanned<EcoString>,
@@ -230,11 +305,74 @@ pub fn json(
     let Spanned { v: path, span } = path;
     let id = vm.location().join(&path).at(span)?;
     let data = vm.world().file(id).at(span)?;
-    let value: serde_json::Value =
-        serde_json::from_slice(&data).map_err(format_json_error).at(span)?;
+    decode_json(Spanned::new(Readable::Bytes(data), span), "json file")
+}
+
+/// Reads structured data from a JSON string or bytes.
+///
+/// The data is converted in the same way as by the [`json`]($func/json)
+/// function.
+///
+/// ## Example { #example }
+/// ```example
+/// #let data = json.decode("{\"name\": \"Typst\", \"stars\": 5}")
+/// #data.name has #data.stars stars.
+/// ```
+///
+/// Display: Decode JSON
+/// Category: data-loading
+#[func]
+pub fn json_decode(
+    /// JSON data.
+    data: Spanned<Readable>,
+) -> SourceResult<Value> {
+    decode_json(data, "json")
+}
+
+/// Decode JSON data, calling it `format` in error messages.
+fn decode_json(data: Spanned<Readable>, format: &str) -> SourceResult<Value> {
+    let Spanned { v: data, span } = data;
+    let data: Bytes = data.into();
+    let value: serde_json::Value = serde_json::from_slice(&data)
+        .map_err(|err| format_json_error(err, format))
+        .at(span)?;
     Ok(convert_json(value))
 }
 
+/// Encodes structured data into a JSON string.
+///
+/// Dictionaries become JSON objects, arrays become JSON arrays, and `{none}`
+/// becomes `null`. Integers are written without and floats with a fractional
+/// part, so that decoding the result yields the same kind of number again.
+/// Values that have no JSON equivalent are written as their representation.
+///
+/// ## Example { #example }
+/// ```example
+/// #json.encode((name: "Typst", stars: 5, ratio: 0.5), pretty: false)
+/// ```
+///
+/// Display: Encode JSON
+/// Category: data-loading
+#[func]
+pub fn json_encode(
+    /// Value to be encoded.
+    value: Spanned<Value>,
+    /// Whether to pretty print the JSON with newlines and indentation.
+    #[named]
+    #[default(true)]
+    pretty: bool,
+) -> SourceResult<Str> {
+    let Spanned { v: value, span } = value;
+    if pretty {
+        serde_json::to_string_pretty(&value)
+    } else {
+        serde_json::to_string(&value)
+    }
+    .map(|v| v.into())
+    .map_err(|err| eco_format!("failed to encode value as json: {err}"))
+    .at(span)
+}
+
 /// Convert a JSON value to a Typst value.
 fn convert_json(value: serde_json::Value) -> Value {
     match value {
@@ -257,9 +395,9 @@ fn convert_json(value: serde_json::Value) -> Value {
 }
 
 /// Format the user-facing JSON error message.
-fn format_json_error(error: serde_json::Error) -> EcoString {
+fn format_json_error(error: serde_json::Error, format: &str) -> EcoString {
     assert!(error.is_syntax() || error.is_eof());
-    eco_format!("failed to parse json file: syntax error in line {}", error.line())
+    eco_format!("failed to parse {format}: syntax error in line {}", error.line())
 }
 
 /// Reads structured data from a TOML file.
@@ -268,8 +406,8 @@ fn format_json_error(error: serde_json::Error) -> EcoString {
 /// converted into Typst dictionaries, and TOML arrays will be converted into
 /// Typst arrays. Strings and booleans will be converted into the Typst
 /// equivalents and numbers will be converted to floats or integers depending on
-/// whether they are whole numbers. For the time being, datetimes will be
-/// converted to strings as Typst does not have a built-in datetime yet.
+/// whether they are whole numbers. Datetimes will be converted into Typst
+/// [datetimes]($type/datetime).
 ///
 /// The TOML file in the example consists of a table with the keys `title`,
 /// `version`, and `authors`.
@@ -287,6 +425,10 @@ fn format_json_error(error: serde_json::Error) -> EcoString {
 /// Display: TOML
 /// Category: data-loading
 #[func]
+#[scope(
+    scope.define("decode", toml_decode_func());
+    scope
+)]
 pub fn toml(
     /// Path to a TOML file.
     path: Spanned<EcoString>,
@@ -296,12 +438,43 @@ pub fn toml(
     let Spanned { v: path, span } = path;
     let id = vm.location().join(&path).at(span)?;
     let data = vm.world().file(id).at(span)?;
+    decode_toml(Spanned::new(Readable::Bytes(data), span), "toml file")
+}
 
-    let raw = std::str::from_utf8(&data)
-        .map_err(|_| "file is not valid utf-8")
-        .at(span)?;
+/// Reads structured data from a TOML string or bytes.
+///
+/// The data is converted in the same way as by the [`toml`]($func/toml)
+/// function.
+///
+/// ## Example { #example }
+/// ```example
+/// #let config = toml.decode("title = \"Report\"\ndate = 2023-07-01")
+/// #config.title from #config.date.display()
+/// ```
+///
+/// Display: Decode TOML
+/// Category: data-loading
+#[func]
+pub fn toml_decode(
+    /// TOML data.
+    data: Spanned<Readable>,
+) -> SourceResult<Value> {
+    decode_toml(data, "toml")
+}
 
-    let value: toml::Value = toml::from_str(raw).map_err(format_toml_error).at(span)?;
+/// Decode TOML data, calling it `format` in error messages.
+fn decode_toml(data: Spanned<Readable>, format: &str) -> SourceResult<Value> {
+    let Spanned { v: data, span } = data;
+    let raw = match &data {
+        Readable::Str(string) => string.as_str(),
+        Readable::Bytes(bytes) => std::str::from_utf8(bytes)
+            .map_err(|_| "file is not valid utf-8")
+            .at(span)?,
+    };
+
+    let value: toml::Value = toml::from_str(raw)
+        .map_err(|err| format_toml_error(err, format))
+        .at(span)?;
     Ok(convert_toml(value))
 }
 
@@ -342,16 +515,16 @@ fn convert_toml(value: toml::Value) -> Value {
 }
 
 /// Format the user-facing TOML error message.
-fn format_toml_error(error: toml::de::Error) -> EcoString {
+fn format_toml_error(error: toml::de::Error, format: &str) -> EcoString {
     if let Some(range) = error.span() {
         eco_format!(
-            "failed to parse toml file: {}, index {}-{}",
+            "failed to parse {format}: {}, index {}-{}",
             error.message(),
             range.start,
             range.end
         )
     } else {
-        eco_format!("failed to parse toml file: {}", error.message())
+        eco_format!("failed to parse {format}: {}", error.message())
     }
 }
 
@@ -396,6 +569,10 @@ fn format_toml_error(error: toml::de::Error) -> EcoString {
 /// Display: YAML
 /// Category: data-loading
 #[func]
+#[scope(
+    scope.define("decode", yaml_decode_func());
+    scope
+)]
 pub fn yaml(
     /// Path to a YAML file.
     path: Spanned<EcoString>,
@@ -405,8 +582,37 @@ pub fn yaml(
     let Spanned { v: path, span } = path;
     let id = vm.location().join(&path).at(span)?;
     let data = vm.world().file(id).at(span)?;
-    let value: serde_yaml::Value =
-        serde_yaml::from_slice(&data).map_err(format_yaml_error).at(span)?;
+    decode_yaml(Spanned::new(Readable::Bytes(data), span), "yaml file")
+}
+
+/// Reads structured data from a YAML string or bytes.
+///
+/// The data is converted in the same way as by the [`yaml`]($func/yaml)
+/// function. YAML has no dedicated datetime type, so dates remain strings.
+///
+/// ## Example { #example }
+/// ```example
+/// #let data = yaml.decode("name: Typst\ntags: [fast, fun]")
+/// #data.name is #data.tags.join(" and ").
+/// ```
+///
+/// Display: Decode YAML
+/// Category: data-loading
+#[func]
+pub fn yaml_decode(
+    /// YAML data.
+    data: Spanned<Readable>,
+) -> SourceResult<Value> {
+    decode_yaml(data, "yaml")
+}
+
+/// Decode YAML data, calling it `format` in error messages.
+fn decode_yaml(data: Spanned<Readable>, format: &str) -> SourceResult<Value> {
+    let Spanned { v: data, span } = data;
+    let data: Bytes = data.into();
+    let value: serde_yaml::Value = serde_yaml::from_slice(&data)
+        .map_err(|err| format_yaml_error(err, format))
+        .at(span)?;
     Ok(convert_yaml(value))
 }
 
@@ -443,8 +649,8 @@ fn convert_yaml_key(key: serde_yaml::Value) -> Option<Str> {
 }
 
 /// Format the user-facing YAML error message.
-fn format_yaml_error(error: serde_yaml::Error) -> EcoString {
-    eco_format!("failed to parse yaml file: {}", error.to_string().trim())
+fn format_yaml_error(error: serde_yaml::Error, format: &str) -> EcoString {
+    eco_format!("failed to parse {format}: {}", error.to_string().trim())
 }
 
 /// Reads structured data from an XML file.
@@ -499,6 +705,10 @@ fn format_yaml_error(error: serde_yaml::Error) -> EcoString {
 /// Display: XML
 /// Category: data-loading
 #[func]
+#[scope(
+    scope.define("decode", xml_decode_func());
+    scope
+)]
 pub fn xml(
     /// Path to an XML file.
     path: Spanned<EcoString>,
@@ -508,8 +718,44 @@ pub fn xml(
     let Spanned { v: path, span } = path;
     let id = vm.location().join(&path).at(span)?;
     let data = vm.world().file(id).at(span)?;
-    let text = std::str::from_utf8(&data).map_err(FileError::from).at(span)?;
-    let document = roxmltree::Document::parse(text).map_err(format_xml_error).at(span)?;
+    decode_xml(Spanned::new(Readable::Bytes(data), span), "xml file")
+}
+
+/// Reads structured data from an XML string or bytes.
+///
+/// The data is parsed into the same structure of element dictionaries and
+/// strings as by the [`xml`]($func/xml) function.
+///
+/// ## Example { #example }
+/// ```example
+/// #let data = xml.decode("<list><item id=\"1\">First</item></list>")
+/// #let item = data.first().children.first()
+/// #item.tag #item.attrs.id: #item.children.first()
+/// ```
+///
+/// Display: Decode XML
+/// Category: data-loading
+#[func]
+pub fn xml_decode(
+    /// XML data.
+    data: Spanned<Readable>,
+) -> SourceResult<Value> {
+    decode_xml(data, "xml")
+}
+
+/// Decode XML data, calling it `format` in error messages.
+fn decode_xml(data: Spanned<Readable>, format: &str) -> SourceResult<Value> {
+    let Spanned { v: data, span } = data;
+    let text = match &data {
+        Readable::Str(string) => string.as_str(),
+        Readable::Bytes(bytes) => {
+            std::str::from_utf8(bytes).map_err(FileError::from).at(span)?
+        }
+    };
+
+    let document = roxmltree::Document::parse(text)
+        .map_err(|err| format_xml_like_error(format, err))
+        .at(span)?;
     Ok(convert_xml(document.root()))
 }
 
@@ -536,8 +782,3 @@ fn convert_xml(node: roxmltree::Node) -> Value {
         "children" => children,
     })
 }
-
-/// Format the user-facing XML error message.
-fn format_xml_error(error: roxmltree::Error) -> EcoString {
-    format_xml_like_error("xml file", error)
-}
@@ -1,6 +1,10 @@
-use typst::eval::EvalMode;
+use std::cmp::Ordering;
+
+use typst::diag::SourceDiagnostic;
+use typst::eval::{EvalMode, Module, Rng};
 
 use crate::prelude::*;
+use crate::text::{LinebreakElem, TextElem};
 
 /// Determines the type of a value.
 ///
@@ -53,6 +57,151 @@ pub fn repr(
     value.repr()
 }
 
+/// Extracts the plain text of content.
+///
+/// Walks through the content and its children, concatenating text, spaces,
+/// line breaks, and raw text. Everything else, like styling or shapes, is
+/// dropped. No layout is performed, so this is cheap and doesn't depend on the
+/// content's position in the document.
+///
+/// ## Example { #example }
+/// ```example
+/// #let title = [The *Great* Gatsby]
+/// #to-string(title) \
+/// #to-string(title).len()
+/// ```
+///
+/// Display: To String
+/// Category: foundations
+#[func]
+pub fn to_string(
+    /// The content to extract text from.
+    content: Content,
+) -> Str {
+    content.plain_text().into()
+}
+
+/// Produces a pseudo-random float between 0 (inclusive) and 1 (exclusive).
+///
+/// The numbers are deterministic: Compiling the same document always yields the
+/// same numbers, on every platform. All numbers of a document are drawn from
+/// one sequence that starts at the document's seed. Every draw advances it,
+/// also within functions, so a function that is called twice returns different
+/// values. The values thus only depend on the order of the draws. Because a
+/// re-evaluation after an edit draws anew from the start, incremental
+/// compilation preserves the sequence.
+///
+/// The seed is zero unless the compiler is configured otherwise. To choose
+/// the sequence within a document, call [`random.seed`]($func/random.seed).
+///
+/// The sequence restarts from the configured seed in the following places, so
+/// the draws there neither continue the document's sequence nor depend on
+/// earlier calls of `random.seed`:
+/// - At the start of every imported or included module.
+/// - On every call of a function during layout, like a show rule or a function
+///   passed to [`locate`]($func/locate). Such calls are evaluated separately
+///   from the code that defines the function.
+/// - In every string evaluated with [`eval`]($func/eval).
+///
+/// ## Example { #example }
+/// ```example
+/// #random.seed(7)
+/// #calc.round(random(), digits: 3) \
+/// #random.int(1, 7) \
+/// #random.shuffle((1, 2, 3, 4))
+/// ```
+///
+/// Display: Random
+/// Category: foundations
+#[func]
+#[scope(
+    scope.define("int", random_int_func());
+    scope.define("shuffle", random_shuffle_func());
+    scope.define("seed", random_seed_func());
+    scope
+)]
+pub fn random(
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> f64 {
+    vm.rng().next_f64()
+}
+
+/// Produces a pseudo-random integer in a range.
+///
+/// ## Example { #example }
+/// ```example
+/// #random.int(1, 7)
+/// ```
+///
+/// Display: Random Integer
+/// Category: foundations
+#[func]
+pub fn random_int(
+    /// The lower bound of the range (inclusive).
+    start: i64,
+    /// The upper bound of the range (exclusive).
+    end: i64,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> StrResult<i64> {
+    if end <= start {
+        bail!("range must not be empty");
+    }
+    let len = (end as i128 - start as i128) as u64;
+    Ok((start as i128 + vm.rng().below(len) as i128) as i64)
+}
+
+/// Shuffles an array into a pseudo-random order.
+///
+/// ## Example { #example }
+/// ```example
+/// #random.shuffle(("a", "b", "c"))
+/// ```
+///
+/// Display: Random Shuffle
+/// Category: foundations
+#[func]
+pub fn random_shuffle(
+    /// The array to shuffle.
+    array: Array,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> Array {
+    let mut items: Vec<Value> = array.into_iter().collect();
+    for i in (1..items.len()).rev() {
+        let j = vm.rng().below(i as u64 + 1) as usize;
+        items.swap(i, j);
+    }
+    items.into_iter().collect()
+}
+
+/// Restarts the pseudo-random sequence from an explicit seed.
+///
+/// All subsequent draws continue from the new seed, including those in
+/// functions that are called afterwards.
+///
+/// ## Example { #example }
+/// ```example
+/// #random.seed(1)
+/// #let a = random()
+/// #random.seed(1)
+/// #(random() == a)
+/// ```
+///
+/// Display: Random Seed
+/// Category: foundations
+#[func]
+pub fn random_seed(
+    /// The seed to start from.
+    seed: i64,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> NoneValue {
+    *vm.rng() = Rng::new(seed as u64);
+    NoneValue
+}
+
 /// Fails with an error.
 ///
 /// ## Example { #example }
@@ -190,6 +339,175 @@ pub fn assert_ne(
     Ok(NoneValue)
 }
 
+/// Compares two values.
+///
+/// Returns `{-1}` if the first value is smaller than the second, `{0}` if they
+/// are equal and `{1}` if the first value is greater. This uses the same
+/// ordering as the comparison operators `<` and `>` and fails with an error
+/// if the values cannot be compared.
+///
+/// Since the result is a plain integer, this is useful for writing custom
+/// comparators that chain multiple comparisons.
+///
+/// ## Example { #example }
+/// ```example
+/// #cmp(1, 2) \
+/// #cmp("b", "a") \
+/// #cmp(2pt, 2pt)
+/// ```
+///
+/// Display: Compare
+/// Category: foundations
+#[func]
+pub fn cmp(
+    /// The first value to compare.
+    left: Value,
+    /// The second value to compare.
+    right: Value,
+) -> StrResult<i64> {
+    Ok(match typst::eval::ops::compare(&left, &right)? {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    })
+}
+
+/// Joins values together.
+///
+/// The values are combined in the same way as the results of the expressions
+/// in a [code block]($scripting/#blocks): Strings are concatenated, content
+/// is sequenced, and `{none}` is ignored. Joining no values results in
+/// `{none}`. Fails with an error if two values cannot be joined.
+///
+/// ## Example { #example }
+/// ```example
+/// #join("a", "b", "c") \
+/// #join([*Bold*], " and ", [_emphasis_]) \
+/// #join(..range(3).map(str))
+/// ```
+///
+/// Display: Join
+/// Category: foundations
+#[func]
+pub fn join(
+    /// The values to join.
+    #[variadic]
+    values: Vec<Value>,
+) -> StrResult<Value> {
+    let mut result = Value::None;
+    for value in values {
+        result = typst::eval::ops::join(result, value)?;
+    }
+    Ok(result)
+}
+
+/// Calls a function and catches the errors it produces.
+///
+/// If the function finishes successfully, its result is returned. Otherwise,
+/// this returns a dictionary describing the first error instead of failing.
+/// The dictionary has the following keys:
+///
+/// - `message`: The error message as a string.
+/// - `hints`: An array of hint strings.
+/// - `span`: A dictionary with the `file`, `line`, and `column` (both
+///   starting at 1) of the error, or `{none}` if the error has no location.
+///
+/// Errors from exceeding a resource limit, like the maximum function call
+/// depth, are not caught.
+///
+/// Since the result of a successful call is returned as is, a dictionary with
+/// a `message` key doesn't necessarily stem from an error: The function might
+/// just have returned such a dictionary, for example when decoding JSON. If
+/// that can happen, wrap the result in a dictionary, so that successful calls
+/// can be told apart by their `value` key.
+///
+/// ## Example { #example }
+/// ```example
+/// #let data = catch(() => (value: json.decode("{oops}")))
+/// #if "value" not in data [
+///   Could not load data: #data.message
+/// ]
+/// ```
+///
+/// Display: Catch
+/// Category: foundations
+#[func]
+pub fn catch(
+    /// The function to call. It is called without arguments.
+    func: Func,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> SourceResult<Value> {
+    let args = Args::new::<Value>(func.span(), []);
+    Ok(match func.call_vm(vm, args) {
+        Ok(value) => value,
+        Err(errors) if errors.iter().any(|error| error.fatal) => return Err(errors),
+        Err(errors) => match errors.first() {
+            Some(error) => describe_error(vm.world(), error).into_value(),
+            None => Value::None,
+        },
+    })
+}
+
+/// Describe an error as a dictionary.
+fn describe_error(world: Tracked<dyn World + '_>, error: &SourceDiagnostic) -> Dict {
+    dict! {
+        "message" => error.message.clone(),
+        "hints" => error.hints.iter().cloned().map(IntoValue::into_value).collect::<Array>(),
+        "span" => describe_span(world, error.span),
+    }
+}
+
+/// Describe the location of a span as a dictionary.
+fn describe_span(world: Tracked<dyn World + '_>, span: Span) -> Option<Dict> {
+    if span.is_detached() {
+        return None;
+    }
+
+    let id = span.id();
+    let source = world.source(id).ok()?;
+    let start = source.find(span)?.offset();
+    Some(dict! {
+        "file" => id.path().to_string_lossy().as_ref(),
+        "line" => source.byte_to_line(start)? + 1,
+        "column" => source.byte_to_column(start)? + 1,
+    })
+}
+
+/// Wraps a function so that its results are cached.
+///
+/// The returned function remembers the result for each combination of
+/// arguments it was called with and returns it again for later calls with the
+/// same arguments instead of calling the original function. When the wrapped
+/// function is a named function, its recursive calls to itself are cached,
+/// too. The cache lives as long as the returned function.
+///
+/// This only makes sense for pure functions, whose result depends on nothing
+/// but their arguments. If the function's result also depends on other state,
+/// like a counter, cached results can be outdated.
+///
+/// The cache holds the results for at most 10 000 different combinations of
+/// arguments. Calls with further arguments are not cached. Warnings produced
+/// by the function are only reported for the call that computes a result, not
+/// for later calls that reuse it.
+///
+/// ## Example { #example }
+/// ```example
+/// #let fib(n) = if n <= 1 { n } else { fib(n - 1) + fib(n - 2) }
+/// #let fib = memoize(fib)
+/// #fib(50)
+/// ```
+///
+/// Display: Memoize
+/// Category: foundations
+#[func]
+pub fn memoize(
+    /// The function whose results to cache.
+    func: Func,
+) -> Func {
+    func.memoized()
+}
+
 /// Evaluates a string as Typst code.
 ///
 /// This function should only be used as a last resort.
@@ -243,5 +561,198 @@ pub fn eval(
     for (key, value) in dict {
         scope.define(key, value);
     }
-    typst::eval::eval_string(vm.world(), &text, span, mode, scope)
+    typst::eval::eval_string(vm.world(), &text, span, mode, scope, vm.limits())
+}
+
+/// Evaluates a string containing a single Typst expression.
+///
+/// In contrast to [`eval`]($func/eval), the string must consist of exactly one
+/// code expression. This makes it suitable for evaluating formulas like in the
+/// cells of a spreadsheet.
+///
+/// ## Example { #example }
+/// ```example
+/// #eval-expr("1 + 2 * 3") \
+/// #eval-expr("calc.pow(x, 2)", scope: (x: 4))
+/// ```
+///
+/// Display: Evaluate Expression
+/// Category: foundations
+#[func]
+pub fn eval_expr(
+    /// A string containing a single Typst expression.
+    ///
+    /// The expression cannot interact with the file system.
+    source: Spanned<String>,
+    /// A scope of definitions that are made available.
+    #[named]
+    #[default]
+    scope: Dict,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> SourceResult<Value> {
+    let Spanned { v: text, span } = source;
+    let dict = scope;
+    let mut scope = Scope::new();
+    for (key, value) in dict {
+        scope.define(key, value);
+    }
+    typst::eval::eval_expr(vm.world(), &text, span, scope, vm.limits())
+}
+
+/// Parses a string containing a literal value.
+///
+/// This is the inverse of [`repr`]($func/repr) for pure data: It accepts
+/// literals like numbers, strings and lengths as well as arrays and
+/// dictionaries of them. Variables, function calls and other computations are
+/// rejected.
+///
+/// ## Example { #example }
+/// ```example
+/// #let data = (1, "two", (three: 3pt))
+/// #parse-value(repr(data)) \
+/// #parse-value("(x: -1.5, y: 2cm)")
+/// ```
+///
+/// Display: Parse Value
+/// Category: foundations
+#[func]
+pub fn parse_value(
+    /// The string to parse.
+    source: Spanned<String>,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> SourceResult<Value> {
+    let Spanned { v: text, span } = source;
+    typst::eval::parse_value(vm.world(), &text, span, vm.limits())
+}
+
+/// A module with functions for testing code.
+///
+/// The module is named `testing` instead of `test`, as Typst's own test suite
+/// defines a global `test` function, which would shadow a module of the same
+/// name.
+pub fn testing_module() -> Module {
+    let mut scope = Scope::new();
+    scope.define("it", it_func());
+    scope.define("describe", describe_func());
+    Module::new("testing").with_scope(scope)
+}
+
+/// Runs a test case.
+///
+/// Calls the given function and records whether it finished without an
+/// error. Errors, such as those from failed assertions, don't abort the
+/// compilation but are recorded as a failure. Returns a dictionary with the
+/// keys `description`, `passed`, and `message`, where `message` is the first
+/// error message of a failed test case or `{none}`. Errors from exceeding a
+/// resource limit, like the maximum function call depth, still abort the
+/// compilation.
+///
+/// ## Example { #example }
+/// ```example
+/// #testing.it("adds numbers", () => {
+///   assert.eq(1 + 1, 2)
+/// })
+/// ```
+///
+/// _Note:_ This function must be specified as `testing.it`, not just `it`.
+///
+/// Display: It
+/// Category: foundations
+#[func]
+pub fn it(
+    /// A description of what the test case checks.
+    description: EcoString,
+    /// The function that performs the checks. It is called without arguments.
+    body: Func,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> SourceResult<TestOutcome> {
+    let args = Args::new::<Value>(body.span(), []);
+    let message = match body.call_vm(vm, args) {
+        Ok(_) => None,
+        Err(errors) if errors.iter().any(|error| error.fatal) => return Err(errors),
+        Err(errors) => Some(
+            errors
+                .first()
+                .map(|error| error.message.clone())
+                .unwrap_or_else(|| "test failed".into()),
+        ),
+    };
+    Ok(TestOutcome { description, message })
+}
+
+/// Summarizes a group of test cases.
+///
+/// Takes the outcomes of `testing.it` calls and produces content that states
+/// how many of them passed and failed, followed by the description and error
+/// message of each failed test case.
+///
+/// ## Example { #example }
+/// ```example
+/// #testing.describe(
+///   "Arithmetic",
+///   testing.it("adds", () => assert(1 + 1 == 2)),
+///   testing.it("divides", () => assert.eq(1 / 2, 2)),
+/// )
+/// ```
+///
+/// _Note:_ This function must be specified as `testing.describe`, not just
+/// `describe`.
+///
+/// Display: Describe
+/// Category: foundations
+#[func]
+pub fn describe(
+    /// The name of the group of test cases.
+    name: EcoString,
+    /// The outcomes of the test cases.
+    #[variadic]
+    outcomes: Vec<TestOutcome>,
+) -> Content {
+    let failed: Vec<_> = outcomes
+        .iter()
+        .filter_map(|outcome| Some((&outcome.description, outcome.message.as_ref()?)))
+        .collect();
+
+    let mut seq = vec![
+        TextElem::packed(name).strong(),
+        TextElem::packed(eco_format!(
+            ": {} passed, {} failed",
+            outcomes.len() - failed.len(),
+            failed.len()
+        )),
+    ];
+
+    for (description, message) in failed {
+        seq.push(LinebreakElem::new().pack());
+        seq.push(TextElem::packed(eco_format!("✗ {description}: {message}")));
+    }
+
+    Content::sequence(seq)
+}
+
+/// The outcome of a test case.
+pub struct TestOutcome {
+    /// The description of the test case.
+    description: EcoString,
+    /// The error message if the test case failed.
+    message: Option<EcoString>,
+}
+
+cast! {
+    TestOutcome,
+    self => dict! {
+        "description" => self.description,
+        "passed" => self.message.is_none(),
+        "message" => self.message,
+    }.into_value(),
+    mut dict: Dict => {
+        let description = dict.take("description")?.cast()?;
+        let message = dict.take("message")?.cast()?;
+        dict.take("passed").ok();
+        dict.finish(&["description", "passed", "message"])?;
+        Self { description, message }
+    },
 }
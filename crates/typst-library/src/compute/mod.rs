@@ -15,9 +15,18 @@ use crate::prelude::*;
 pub(super) fn define(global: &mut Scope) {
     global.define("type", type_func());
     global.define("repr", repr_func());
+    global.define("to-string", to_string_func());
+    global.define("random", random_func());
     global.define("panic", panic_func());
     global.define("assert", assert_func());
+    global.define("cmp", cmp_func());
+    global.define("join", join_func());
+    global.define("catch", catch_func());
+    global.define("memoize", memoize_func());
     global.define("eval", eval_func());
+    global.define("eval-expr", eval_expr_func());
+    global.define("parse-value", parse_value_func());
+    global.define("testing", testing_module());
     global.define("int", int_func());
     global.define("float", float_func());
     global.define("luma", luma_func());
@@ -31,6 +40,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define("label", label_func());
     global.define("regex", regex_func());
     global.define("array", array_func());
+    global.define("dictionary", dictionary_func());
     global.define("range", range_func());
     global.define("read", read_func());
     global.define("csv", csv_func());
@@ -53,7 +53,8 @@ use super::GridLayouter;
 /// - Starting a line with a plus sign creates an automatically numbered
 ///   enumeration item.
 /// - Starting a line with a number followed by a dot creates an explicitly
-///   numbered enumeration item.
+///   numbered enumeration item. Automatically numbered items that follow it
+///   continue counting from its number.
 ///
 /// Enumeration items can contain multiple paragraphs and other block-level
 /// content. All content that is indented more than an item's plus sign or dot
@@ -75,7 +76,8 @@ pub struct EnumElem {
     /// In markup mode, the value of this parameter is determined based on
     /// whether items are separated with a blank line. If items directly follow
     /// each other, this is set to `{true}`; if items are separated by a blank
-    /// line, this is set to `{false}`.
+    /// line, this is set to `{false}`. Two or more blank lines end the list
+    /// instead.
     ///
     /// ```example
     /// + If an enum has a lot of text, and
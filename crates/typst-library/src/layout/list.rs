@@ -32,7 +32,11 @@ use super::GridLayouter;
 /// This functions also has dedicated syntax: Start a line with a hyphen,
 /// followed by a space to create a list item. A list item can contain multiple
 /// paragraphs and other block-level content. All content that is indented
-/// more than an item's hyphen becomes part of that item.
+/// more than an item's hyphen becomes part of that item. For this purpose, a
+/// tab advances the indentation to the next multiple of four columns.
+///
+/// An item whose body starts with `[ ]` or `[x]` becomes a task and is
+/// displayed with a checkbox instead of the marker.
 ///
 /// Display: Bullet List
 /// Category: layout
@@ -50,7 +54,8 @@ pub struct ListElem {
     /// In markup mode, the value of this parameter is determined based on
     /// whether items are separated with a blank line. If items directly follow
     /// each other, this is set to `{true}`; if items are separated by a blank
-    /// line, this is set to `{false}`.
+    /// line, this is set to `{false}`. Two or more blank lines end the list
+    /// instead.
     ///
     /// ```example
     /// - If a list has a lot of text, and
@@ -145,7 +150,10 @@ impl Layout for ListElem {
         let mut cells = vec![];
         for item in self.children() {
             cells.push(Content::empty());
-            cells.push(marker.clone());
+            cells.push(match item.checked(styles) {
+                Some(checked) => checkbox(checked),
+                None => marker.clone(),
+            });
             cells.push(Content::empty());
             cells.push(item.body().styled(Self::set_depth(Depth)));
         }
@@ -173,6 +181,20 @@ impl Layout for ListElem {
 /// Category: layout
 #[element]
 pub struct ListItem {
+    /// Whether the item is a task that is done (`{true}`), a task that is
+    /// still open (`{false}`), or a regular item (`{none}`).
+    ///
+    /// Tasks are displayed with a checkbox instead of the list's marker. In
+    /// markup, an item becomes a task by starting its body with `[ ]` or
+    /// `[x]`.
+    ///
+    /// ```example
+    /// - [x] Write the draft
+    /// - [ ] Proofread it
+    /// - Celebrate
+    /// ```
+    pub checked: Option<bool>,
+
     /// The item's body.
     #[required]
     pub body: Content,
@@ -183,6 +205,11 @@ cast! {
     v: Content => v.to::<Self>().cloned().unwrap_or_else(|| Self::new(v.clone())),
 }
 
+/// The checkbox that replaces the marker of a task item.
+fn checkbox(checked: bool) -> Content {
+    TextElem::packed(if checked { '☒' } else { '☐' }).aligned(Align::LEFT_TOP.into())
+}
+
 /// A list's marker.
 #[derive(Debug, Clone, Hash)]
 pub enum ListMarker {
@@ -630,6 +630,14 @@ impl<'a> ListBuilder<'a> {
         if !self.items.is_empty()
             && (content.is::<SpaceElem>() || content.is::<ParbreakElem>())
         {
+            // Two or more blank lines end the list.
+            if content
+                .to::<ParbreakElem>()
+                .map_or(false, |elem| elem.ends_list(styles))
+            {
+                return false;
+            }
+
             self.staged.push((content, styles));
             return true;
         }
@@ -235,7 +235,13 @@ pub enum Linebreaks {
 /// Display: Paragraph Break
 /// Category: layout
 #[element(Unlabellable)]
-pub struct ParbreakElem {}
+pub struct ParbreakElem {
+    /// Whether the break consists of two or more blank lines in markup, which
+    /// ends a list instead of separating its items.
+    #[internal]
+    #[default(false)]
+    pub ends_list: bool,
+}
 
 impl Unlabellable for ParbreakElem {}
 
@@ -598,11 +604,13 @@ fn collect<'a>(
             if SmartQuoteElem::enabled_in(styles) {
                 let lang = TextElem::lang_in(styles);
                 let region = TextElem::region_in(styles);
+                let custom = SmartQuoteElem::quotes_in(styles);
                 let quotes = Quotes::from_lang(
                     lang,
                     region,
                     SmartQuoteElem::alternative_in(styles),
-                );
+                )
+                .with_overrides(&custom);
                 let peeked = iter.peek().and_then(|child| {
                     let child = if let Some((child, _)) = child.to_styled() {
                         child
@@ -15,9 +15,9 @@ pub mod text;
 pub mod visualize;
 
 use typst::diag::At;
-use typst::eval::{LangItems, Library, Module, Scope};
+use typst::eval::{LangItems, Library, Limits, Module, Scope};
 use typst::geom::Smart;
-use typst::model::{Element, Styles};
+use typst::model::{Content, Element, Styles};
 
 use self::layout::LayoutRoot;
 
@@ -25,7 +25,14 @@ use self::layout::LayoutRoot;
 pub fn build() -> Library {
     let math = math::module();
     let global = global(math.clone());
-    Library { global, math, styles: styles(), items: items() }
+    Library {
+        global,
+        math,
+        styles: styles(),
+        items: items(),
+        limits: Limits::default(),
+        seed: 0,
+    }
 }
 
 /// Construct the module with global definitions.
@@ -62,7 +69,10 @@ fn items() -> LangItems {
         text_func: text::TextElem::func(),
         text_str: |content| Some(content.to::<text::TextElem>()?.text()),
         smart_quote: |double| text::SmartQuoteElem::new().with_double(double).pack(),
-        parbreak: || layout::ParbreakElem::new().pack(),
+        parbreak: |ends_list| {
+            let elem = layout::ParbreakElem::new();
+            if ends_list { elem.with_ends_list(true) } else { elem }.pack()
+        },
         strong: |body| text::StrongElem::new(body).pack(),
         emph: |body| text::EmphElem::new(body).pack(),
         raw: |text, lang, block| {
@@ -83,10 +93,22 @@ fn items() -> LangItems {
             }
             elem.pack()
         },
+        footnote: |name| {
+            meta::FootnoteElem::with_content(Content::empty())
+                .with_name(Some(name))
+                .pack()
+        },
+        footnote_def: |name, body| meta::FootnoteElem::define(name, body),
         bibliography_keys: meta::BibliographyElem::keys,
         heading: |level, title| meta::HeadingElem::new(title).with_level(level).pack(),
         heading_func: meta::HeadingElem::func(),
-        list_item: |body| layout::ListItem::new(body).pack(),
+        list_item: |checked, body| {
+            let mut elem = layout::ListItem::new(body);
+            if let Some(checked) = checked {
+                elem.push_checked(Some(checked));
+            }
+            elem.pack()
+        },
         enum_item: |number, body| {
             let mut elem = layout::EnumItem::new(body);
             if let Some(number) = number {
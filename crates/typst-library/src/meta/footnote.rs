@@ -1,7 +1,7 @@
 use comemo::Prehashed;
 use std::str::FromStr;
 
-use super::{Counter, Numbering, NumberingPattern};
+use super::{Counter, Numbering, NumberingPattern, State, StateUpdate};
 use crate::layout::{HElem, ParElem};
 use crate::meta::{Count, CounterUpdate};
 use crate::prelude::*;
@@ -57,6 +57,24 @@ cast! {
 /// And the online app. #footnote(<fn>)
 /// ```
 ///
+/// ## Syntax { #syntax }
+/// Footnotes can also be written with markup: Mark the spot with a name in
+/// brackets and a caret, like `[[^1]]`, and define the note's content on its
+/// own line as `[[^1]: Content]`. The definition can appear anywhere in the
+/// same file and may continue on indented lines. Its content is evaluated
+/// where it is defined. The first reference to a name in the document shows
+/// the note and is labelled with the name, like `[<^1>]`. Later references to
+/// the same name, also from other calls of a function, point back to it
+/// instead of creating a new note.
+///
+/// ```example
+/// Typst is fast[^speed] and
+/// really fast[^speed].
+///
+/// [^speed]: Thanks to incremental
+///   compilation.
+/// ```
+///
 /// _Note:_ Set and show rules in the scope where `footnote` is called may not
 /// apply to the footnote's content. See [here][issue] more information.
 ///
@@ -91,6 +109,11 @@ pub struct FootnoteElem {
     /// footnote this one should point to.
     #[required]
     pub body: FootnoteBody,
+
+    /// The name of the markup definition this footnote shows: The `1` in
+    /// `[[^1]]`.
+    #[internal]
+    pub name: Option<EcoString>,
 }
 
 impl FootnoteElem {
@@ -104,6 +127,14 @@ impl FootnoteElem {
         Self::new(FootnoteBody::Reference(label))
     }
 
+    /// Defines the content of the footnotes with the given name.
+    ///
+    /// This produces invisible content that sets the state holding the
+    /// definition.
+    pub fn define(name: EcoString, body: Content) -> Content {
+        definition_state(&name).update(StateUpdate::Set(body.into_value()))
+    }
+
     /// Tests if this footnote is a reference to another footnote.
     pub fn is_ref(&self) -> bool {
         matches!(self.body(), FootnoteBody::Reference(_))
@@ -133,12 +164,34 @@ impl FootnoteElem {
 }
 
 impl Synthesize for FootnoteElem {
-    fn synthesize(&mut self, _vt: &mut Vt, styles: StyleChain) -> SourceResult<()> {
+    fn synthesize(&mut self, vt: &mut Vt, styles: StyleChain) -> SourceResult<()> {
         self.push_numbering(self.numbering(styles));
+
+        // Only the first footnote with a name in the document shows the
+        // definition, later ones refer to it.
+        if let Some(name) = self.name(styles) {
+            let label = Label(eco_format!("^{name}"));
+            let location = self.0.location().unwrap();
+            let selector = Self::func().where_(dict! { "name" => name.clone() });
+            let first = vt.introspector.query_first(&selector);
+            if first.map_or(true, |first| first.location() == Some(location)) {
+                let body = definition_state(&name).final_(vt, location)?;
+                self.push_body(FootnoteBody::Content(body.display()));
+                self.0.push_field("label", label);
+            } else {
+                self.push_body(FootnoteBody::Reference(label));
+            }
+        }
+
         Ok(())
     }
 }
 
+/// The state holding the definition of the footnotes with the given name.
+fn definition_state(name: &str) -> State {
+    State::new(eco_format!("footnote:{name}").into(), Value::None)
+}
+
 impl Show for FootnoteElem {
     #[tracing::instrument(name = "FootnoteElem::show", skip_all)]
     fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
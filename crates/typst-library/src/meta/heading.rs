@@ -1,7 +1,12 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use typst::font::FontWeight;
 use typst::util::option_eq;
 
-use super::{Counter, CounterUpdate, LocalName, Numbering, Outlinable, Refable};
+use super::{
+    BibliographyElem, Counter, CounterUpdate, LocalName, Numbering, Outlinable, Refable,
+};
 use crate::layout::{BlockElem, HElem, VElem};
 use crate::meta::{Count, Supplement};
 use crate::prelude::*;
@@ -120,9 +125,33 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub bookmarked: Smart<bool>,
 
+    /// Whether the heading should automatically get a label derived from its
+    /// text if it doesn't have one already.
+    ///
+    /// The label is a lowercase slug of the heading's text: `[= My Section]`
+    /// can be referenced as `[@my-section]`. If multiple headings share the
+    /// same slug, later ones get a numeric suffix (`my-section-1`,
+    /// `my-section-2`, and so on). Explicit labels take precedence, both on
+    /// the heading itself and elsewhere in the document, and so do the keys
+    /// of the bibliography. A heading whose label would collide with one of
+    /// them stays unlabelled.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.", auto-label: true)
+    ///
+    /// = Getting Started
+    /// See @getting-started.
+    /// ```
+    #[default(false)]
+    pub auto_label: bool,
+
     /// The heading's title.
     #[required]
     pub body: Content,
+
+    /// The slug the automatic label is derived from, if any.
+    #[synthesized]
+    pub slug: Option<EcoString>,
 }
 
 impl Synthesize for HeadingElem {
@@ -140,10 +169,86 @@ impl Synthesize for HeadingElem {
         self.push_outlined(self.outlined(styles));
         self.push_bookmarked(self.bookmarked(styles));
 
+        // Derive a label from the heading's text. The label itself is
+        // assigned once the heading is known to the introspector.
+        let slug = (self.auto_label(styles) && self.0.label().is_none())
+            .then(|| slugify(&self.body().plain_text()))
+            .filter(|slug| !slug.is_empty());
+
+        if slug.is_some() {
+            let location = self.0.location().unwrap();
+            if let Some(label) = auto_labels(vt.introspector).0.get(&location) {
+                self.0.push_field("label", label.clone());
+            }
+        }
+
+        self.push_slug(slug);
         Ok(())
     }
 }
 
+/// The automatic labels of the headings in a document, by location.
+struct AutoLabels(HashMap<Location, Label>);
+
+/// Determines the automatic labels of all headings at once, disambiguating
+/// headings with the same slug by their order.
+#[comemo::memoize]
+fn auto_labels(introspector: Tracked<Introspector>) -> Arc<AutoLabels> {
+    let headings = introspector.query(&HeadingElem::func().select());
+
+    // Labels assigned explicitly and bibliography keys can't be taken.
+    let mut taken: HashSet<EcoString> = introspector
+        .query_labelled()
+        .iter()
+        .filter(|elem| elem.to::<HeadingElem>().map_or(true, |h| h.slug().is_none()))
+        .filter_map(|elem| elem.label().map(|label| label.0.clone()))
+        .collect();
+    taken.extend(BibliographyElem::keys(introspector).into_iter().map(|(key, _)| key));
+
+    let mut counts = HashMap::new();
+    let mut labels = HashMap::new();
+    for elem in &headings {
+        let Some(base) = elem.to::<HeadingElem>().and_then(HeadingElem::slug) else {
+            continue;
+        };
+
+        let count = counts.entry(base.clone()).or_insert(0);
+        let label = match *count {
+            0 => base,
+            n => eco_format!("{base}-{n}"),
+        };
+        *count += 1;
+
+        if !taken.contains(&label) {
+            labels.insert(elem.location().unwrap(), Label(label));
+        }
+    }
+
+    Arc::new(AutoLabels(labels))
+}
+
+/// Turns text into a label-friendly slug: Alphanumeric characters are
+/// lowercased, runs of whitespace, dashes and underscores become a single
+/// dash, and everything else is dropped.
+fn slugify(text: &str) -> EcoString {
+    let mut slug = EcoString::new();
+    let mut dash = false;
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            dash = false;
+            for lower in c.to_lowercase() {
+                slug.push(lower);
+            }
+        } else if c.is_whitespace() || c == '-' || c == '_' {
+            dash = true;
+        }
+    }
+    slug
+}
+
 impl Show for HeadingElem {
     #[tracing::instrument(name = "HeadingElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
@@ -255,6 +255,11 @@ pub struct State {
 }
 
 impl State {
+    /// Create a new state identified by a key.
+    pub fn new(key: Str, init: Value) -> Self {
+        Self { key, init }
+    }
+
     /// Call a method on a state.
     #[tracing::instrument(skip(vm))]
     pub fn call_method(
@@ -42,7 +42,7 @@ impl PlainText for SpaceElem {
 ///
 /// Display: Line Break
 /// Category: text
-#[element(Behave)]
+#[element(Behave, PlainText)]
 pub struct LinebreakElem {
     /// Whether to justify the line before the break.
     ///
@@ -67,6 +67,12 @@ impl Behave for LinebreakElem {
     }
 }
 
+impl PlainText for LinebreakElem {
+    fn plain_text(&self, text: &mut EcoString) {
+        text.push('\n');
+    }
+}
+
 /// Strongly emphasizes content by increasing the font weight.
 ///
 /// Increases the current font weight by a given `delta`.
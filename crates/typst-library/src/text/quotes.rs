@@ -55,6 +55,25 @@ pub struct SmartQuoteElem {
     /// ```
     #[default(false)]
     pub alternative: bool,
+
+    /// The quotes to use.
+    ///
+    /// - When set to `{auto}`, the appropriate quotes for the
+    ///   [text language]($func/text.lang) will be used. This is the default.
+    /// - Custom quotes can be passed as a string or an array of two strings,
+    ///   which are then used as the opening and closing double quotes.
+    /// - A dictionary with the keys `single` and/or `double` overrides only
+    ///   the given kind of quote, keeping the language's default for the
+    ///   other one.
+    ///
+    /// ```example
+    /// #set text(lang: "de")
+    /// #set smartquote(quotes: (single: ("›", "‹"), double: "»«"))
+    ///
+    /// "Das ist in 'anderen' Anführungszeichen."
+    /// ```
+    #[default(Smart::Auto)]
+    pub quotes: Smart<QuoteDict>,
 }
 
 /// State machine for smart quote substitution.
@@ -180,6 +199,21 @@ impl<'s> Quotes<'s> {
         }
     }
 
+    /// Replace the quotes with the ones given in a custom quote dictionary.
+    pub fn with_overrides(mut self, dict: &'s Smart<QuoteDict>) -> Self {
+        if let Smart::Custom(dict) = dict {
+            if let Some(single) = &dict.single {
+                self.single_open = &single.open;
+                self.single_close = &single.close;
+            }
+            if let Some(double) = &dict.double {
+                self.double_open = &double.open;
+                self.double_close = &double.close;
+            }
+        }
+        self
+    }
+
     /// The opening quote.
     fn open(&self, double: bool) -> &'s str {
         if double {
@@ -228,3 +262,68 @@ impl Default for Quotes<'_> {
         }
     }
 }
+
+/// A pair of opening and closing quotes.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct QuoteSet {
+    /// The opening quote.
+    pub open: EcoString,
+    /// The closing quote.
+    pub close: EcoString,
+}
+
+cast! {
+    QuoteSet,
+    self => array![self.open, self.close].into_value(),
+    string: EcoString => {
+        let mut chars = string.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(open), Some(close), None) => Self {
+                open: open.into(),
+                close: close.into(),
+            },
+            _ => bail!("expected 2 characters, found {}", string.chars().count()),
+        }
+    },
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(open), Some(close), None) => Self {
+                open: open.cast()?,
+                close: close.cast()?,
+            },
+            _ => bail!("quote array must contain exactly two entries"),
+        }
+    },
+}
+
+/// Custom single and double quotes.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct QuoteDict {
+    /// The single quotes, if overridden.
+    pub single: Option<QuoteSet>,
+    /// The double quotes, if overridden.
+    pub double: Option<QuoteSet>,
+}
+
+cast! {
+    QuoteDict,
+    self => {
+        let mut dict = Dict::new();
+        if let Some(single) = self.single {
+            dict.insert("single".into(), single.into_value());
+        }
+        if let Some(double) = self.double {
+            dict.insert("double".into(), double.into_value());
+        }
+        Value::Dict(dict)
+    },
+    double: QuoteSet => Self { single: None, double: Some(double) },
+    mut dict: Dict => {
+        let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
+        let single = take("single")?;
+        let double = take("double")?;
+        dict.finish(&["single", "double"])?;
+        Self { single, double }
+    },
+}
@@ -38,6 +38,12 @@ use crate::prelude::*;
 /// backticks (`` ` ``) to make it raw. Two backticks produce empty raw text.
 /// When you use three or more backticks, you can additionally specify a
 /// language tag for syntax highlighting directly after the opening backticks.
+/// Inline raw text with a single backtick can carry a language tag, too: Write
+/// it in lowercase, followed by a colon and a space, as in
+/// ``[`rust: let x = 1`]``. The tag is only recognized if it names a known
+/// language. Thus, inline code like ``[`h:mm:ss`]`` or ``[`css:hover`]``
+/// stays as is. To show text like ``[`c: int`]`` literally, use three
+/// backticks and a leading space: ``[``` c: int```]``.
 /// Within raw blocks, everything is rendered as is, in particular, there are no
 /// escape sequences.
 ///
@@ -113,6 +119,23 @@ pub struct RawElem {
     /// ````
     pub lang: Option<EcoString>,
 
+    /// Whether to guess the language of raw blocks without a language tag.
+    ///
+    /// The guess is conservative: Only unambiguous markers like a shebang line
+    /// or an XML declaration are considered. If no language can be determined,
+    /// the text is not highlighted.
+    ///
+    /// ````example
+    /// #set raw(infer-lang: true)
+    ///
+    /// ```
+    /// #!/usr/bin/env python3
+    /// print("Hello World!")
+    /// ```
+    /// ````
+    #[default(false)]
+    pub infer_lang: bool,
+
     /// The horizontal alignment that each line in a raw block should have.
     /// This option is ignored if this is not a raw block (if specified
     /// `block: false` or single backticks were used in markup mode).
@@ -172,38 +195,67 @@ pub struct RawElem {
     /// #let hi = "Hello World"
     /// ```
     /// ````
+    ///
+    /// Instead of a path, you can also give the name of a built-in theme:
+    /// `{"typst-light"}` (the default) or `{"typst-dark"}`. A theme file of the
+    /// same name takes precedence over a built-in theme.
+    ///
+    /// ````example
+    /// #set raw(theme: "typst-dark")
+    /// #show raw: block.with(
+    ///   fill: luma(30),
+    ///   inset: 6pt,
+    /// )
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello World!");
+    /// }
+    /// ```
+    /// ````
     #[parse(
-        let (theme_path, theme_data) = parse_theme(vm, args)?;
-        theme_path.map(Some)
+        let theme = parse_theme(vm, args)?;
+        theme.as_ref().map(|(path, _)| Some(path.clone()))
     )]
     pub theme: Option<EcoString>,
 
     /// The raw file buffer of syntax theme file.
     #[internal]
-    #[parse(theme_data.map(Some))]
+    #[parse(theme.map(|(_, data)| data))]
     pub theme_data: Option<Bytes>,
 }
 
 impl RawElem {
     /// The supported language names and tags.
-    pub fn languages() -> Vec<(&'static str, Vec<&'static str>)> {
-        SYNTAXES
-            .syntaxes()
-            .iter()
-            .map(|syntax| {
-                (
-                    syntax.name.as_str(),
-                    syntax.file_extensions.iter().map(|s| s.as_str()).collect(),
-                )
-            })
-            .chain([("Typst", vec!["typ"]), ("Typst (code)", vec!["typc"])])
-            .collect()
+    pub fn languages() -> &'static [(&'static str, Vec<&'static str>)] {
+        static LANGUAGES: Lazy<Vec<(&'static str, Vec<&'static str>)>> =
+            Lazy::new(|| {
+                SYNTAXES
+                    .syntaxes()
+                    .iter()
+                    .map(|syntax| {
+                        (
+                            syntax.name.as_str(),
+                            syntax.file_extensions.iter().map(|s| s.as_str()).collect(),
+                        )
+                    })
+                    .chain([("Typst", vec!["typ"]), ("Typst (code)", vec!["typc"])])
+                    .collect()
+            });
+        &LANGUAGES
     }
 }
 
 impl Synthesize for RawElem {
     fn synthesize(&mut self, _vt: &mut Vt, styles: StyleChain) -> SourceResult<()> {
-        self.push_lang(self.lang(styles));
+        let lang = self.lang(styles).or_else(|| {
+            if self.block(styles) && self.infer_lang(styles) {
+                infer_lang(&self.text())
+            } else {
+                None
+            }
+        });
+        self.push_lang(lang);
         Ok(())
     }
 }
@@ -222,11 +274,16 @@ impl Show for RawElem {
             load_syntaxes(&self.syntaxes(styles), &self.syntaxes_data(styles)).unwrap()
         });
 
-        let theme = self.theme(styles).map(|theme_path| {
-            load_theme(theme_path, self.theme_data(styles).unwrap()).unwrap()
-        });
+        let theme_path = self.theme(styles);
+        let loaded = theme_path
+            .clone()
+            .zip(self.theme_data(styles))
+            .map(|(path, data)| load_theme(path, data).unwrap());
 
-        let theme = theme.as_deref().unwrap_or(&THEME);
+        let theme = loaded
+            .as_deref()
+            .or_else(|| theme_path.as_deref().and_then(builtin_theme))
+            .unwrap_or(&THEME);
 
         let foreground = theme
             .settings
@@ -366,6 +423,48 @@ fn highlight_themed<F>(
     }
 }
 
+/// Guess the language of raw text from its first line.
+///
+/// Returns `None` unless the first line unambiguously identifies a language.
+fn infer_lang(text: &str) -> Option<EcoString> {
+    let first = text.lines().next()?.trim();
+
+    if let Some(shebang) = first.strip_prefix("#!") {
+        let mut parts = shebang.split_whitespace();
+        let mut program = parts.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = parts.find(|part| !part.starts_with('-'))?;
+        }
+
+        let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        let lang = match program {
+            "sh" | "bash" | "zsh" | "dash" | "ksh" => "bash",
+            "python" => "python",
+            "ruby" => "ruby",
+            "perl" => "perl",
+            "node" => "js",
+            "php" => "php",
+            "lua" => "lua",
+            _ => return None,
+        };
+
+        return Some(lang.into());
+    }
+
+    let lower = first.to_lowercase();
+    let lang = if lower.starts_with("<?xml") {
+        "xml"
+    } else if lower.starts_with("<!doctype html") {
+        "html"
+    } else if lower.starts_with("<?php") {
+        "php"
+    } else {
+        return None;
+    };
+
+    Some(lang.into())
+}
+
 /// Style a piece of text with a syntect style.
 fn styled(piece: &str, foreground: Paint, style: synt::Style) -> Content {
     let mut body = TextElem::packed(piece);
@@ -474,24 +573,43 @@ fn load_theme(path: EcoString, bytes: Bytes) -> StrResult<Arc<synt::Theme>> {
 
 /// Function to parse the theme argument.
 /// Much nicer than having it be part of the `element` macro.
+///
+/// Returns the theme's path or name and, for theme files, their contents.
 fn parse_theme(
     vm: &mut Vm,
     args: &mut Args,
-) -> SourceResult<(Option<EcoString>, Option<Bytes>)> {
-    let Some(Spanned { v: path, span }) =
-        args.named::<Spanned<EcoString>>("theme")?
+) -> SourceResult<Option<(EcoString, Option<Bytes>)>> {
+    let Some(Spanned { v: path, span }) = args.named::<Spanned<EcoString>>("theme")?
     else {
-        return Ok((None, None));
+        return Ok(None);
     };
 
-    // Load theme file.
+    // Load the theme file, falling back to a built-in theme of that name.
     let id = vm.location().join(&path).at(span)?;
-    let data = vm.world().file(id).at(span)?;
+    let data = match vm.world().file(id) {
+        Ok(data) => data,
+        Err(_) if builtin_theme(&path).is_some() => return Ok(Some((path, None))),
+        Err(_) if !path.contains(['.', '/']) => {
+            return Err(eco_format!("unknown theme `{path}`"))
+                .hint("the built-in themes are `typst-light` and `typst-dark`")
+                .at(span);
+        }
+        Err(error) => return Err(error).at(span),
+    };
 
     // Check that parsing works.
     let _ = load_theme(path.clone(), data.clone()).at(span)?;
 
-    Ok((Some(path), Some(data)))
+    Ok(Some((path, Some(data))))
+}
+
+/// Looks up a built-in theme by name.
+fn builtin_theme(name: &str) -> Option<&'static synt::Theme> {
+    match name {
+        "typst-light" => Some(&THEME),
+        "typst-dark" => Some(&DARK_THEME),
+        _ => None,
+    }
 }
 
 /// The syntect syntax definitions.
@@ -557,6 +675,44 @@ pub static THEME: Lazy<synt::Theme> = Lazy::new(|| synt::Theme {
     ],
 });
 
+/// A dark theme for syntax highlighting.
+pub static DARK_THEME: Lazy<synt::Theme> = Lazy::new(|| synt::Theme {
+    name: Some("Typst Dark".into()),
+    author: Some("The Typst Project Developers".into()),
+    settings: synt::ThemeSettings {
+        foreground: Some(to_syn("#d4d4d4".parse::<RgbaColor>().unwrap())),
+        ..synt::ThemeSettings::default()
+    },
+    scopes: vec![
+        item("comment", Some("#7d8590"), None),
+        item("constant.character.escape", Some("#56b6c2"), None),
+        item("markup.bold", None, Some(synt::FontStyle::BOLD)),
+        item("markup.italic", None, Some(synt::FontStyle::ITALIC)),
+        item("markup.underline", None, Some(synt::FontStyle::UNDERLINE)),
+        item("markup.raw", Some("#9a9a9a"), None),
+        item("string.other.math.typst", None, None),
+        item("punctuation.definition.math", Some("#98c379"), None),
+        item("keyword.operator.math", Some("#56b6c2"), None),
+        item("markup.heading, entity.name.section", None, Some(synt::FontStyle::BOLD)),
+        item(
+            "markup.heading.typst",
+            None,
+            Some(synt::FontStyle::BOLD | synt::FontStyle::UNDERLINE),
+        ),
+        item("punctuation.definition.list", Some("#c678dd"), None),
+        item("markup.list.term", None, Some(synt::FontStyle::BOLD)),
+        item("entity.name.label, markup.other.reference", Some("#56b6c2"), None),
+        item("keyword, constant.language, variable.language", Some("#ff7b72"), None),
+        item("storage.type, storage.modifier", Some("#ff7b72"), None),
+        item("constant", Some("#d19a66"), None),
+        item("string", Some("#98c379"), None),
+        item("entity.name, variable.function, support", Some("#61afef"), None),
+        item("support.macro", Some("#56b6c2"), None),
+        item("meta.annotation", Some("#e5c07b"), None),
+        item("entity.other, meta.interpolation", Some("#c678dd"), None),
+    ],
+});
+
 /// Create a syntect theme item.
 fn item(
     scope: &str,
@@ -102,6 +102,10 @@ pub enum Expr {
     Label(Label),
     /// A reference: `@target`, `@target[..]`.
     Ref(Ref),
+    /// A reference to a footnote: `[^note]`.
+    FootnoteRef(FootnoteRef),
+    /// The definition of a footnote: `[^note]: Details`.
+    FootnoteDef(FootnoteDef),
     /// A section heading: `= Introduction`.
     Heading(Heading),
     /// An item in a bullet list: `- ...`.
@@ -214,6 +218,8 @@ impl AstNode for Expr {
             SyntaxKind::Link => node.cast().map(Self::Link),
             SyntaxKind::Label => node.cast().map(Self::Label),
             SyntaxKind::Ref => node.cast().map(Self::Ref),
+            SyntaxKind::FootnoteRef => node.cast().map(Self::FootnoteRef),
+            SyntaxKind::FootnoteDef => node.cast().map(Self::FootnoteDef),
             SyntaxKind::Heading => node.cast().map(Self::Heading),
             SyntaxKind::ListItem => node.cast().map(Self::List),
             SyntaxKind::EnumItem => node.cast().map(Self::Enum),
@@ -276,6 +282,8 @@ impl AstNode for Expr {
             Self::Link(v) => v.as_untyped(),
             Self::Label(v) => v.as_untyped(),
             Self::Ref(v) => v.as_untyped(),
+            Self::FootnoteRef(v) => v.as_untyped(),
+            Self::FootnoteDef(v) => v.as_untyped(),
             Self::Heading(v) => v.as_untyped(),
             Self::List(v) => v.as_untyped(),
             Self::Enum(v) => v.as_untyped(),
@@ -406,6 +414,13 @@ node! {
     Parbreak
 }
 
+impl Parbreak {
+    /// The number of blank lines that make up the paragraph break.
+    pub fn blank_lines(&self) -> usize {
+        split_newlines(self.0.text()).len().saturating_sub(2)
+    }
+}
+
 node! {
     /// An escape sequence: `\#`, `\u{1F5FA}`.
     Escape
@@ -615,6 +630,37 @@ impl Raw {
         let text = self.0.text();
         text.starts_with("```") && text.chars().any(is_newline)
     }
+
+    /// A possible language tag in front of single-backtick raw text like
+    /// `` `rust: let x = 1` ``.
+    ///
+    /// The candidate is a lowercase ASCII identifier followed by a colon, a
+    /// single space and a character that isn't whitespace. Requiring the space
+    /// keeps inline code like `` `h:mm:ss` `` or `` `localhost:8080` `` intact.
+    /// Whether the candidate actually names a language must be checked by the
+    /// caller.
+    pub fn inline_lang(&self) -> Option<&str> {
+        let inner = self.0.text().strip_prefix('`')?.strip_suffix('`')?;
+        if inner.starts_with('`') {
+            return Option::None;
+        }
+
+        let mut s = Scanner::new(inner);
+        if !s.eat_if(|c: char| c.is_ascii_lowercase()) {
+            return Option::None;
+        }
+
+        s.eat_while(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit());
+        let lang = s.before();
+        if !s.eat_if(": ") {
+            return Option::None;
+        }
+
+        match s.peek() {
+            Some(c) if !c.is_whitespace() => Some(lang),
+            _ => Option::None,
+        }
+    }
 }
 
 node! {
@@ -662,6 +708,39 @@ impl Ref {
     }
 }
 
+node! {
+    /// A reference to a footnote: `[^note]`.
+    FootnoteRef
+}
+
+impl FootnoteRef {
+    /// Get the name of the footnote.
+    pub fn get(&self) -> &str {
+        self.0.text().trim_start_matches("[^").trim_end_matches(']')
+    }
+}
+
+node! {
+    /// The definition of a footnote: `[^note]: Details`.
+    FootnoteDef
+}
+
+impl FootnoteDef {
+    /// The name of the defined footnote.
+    pub fn name(&self) -> &str {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::FootnoteRef)
+            .map(|node| node.text().trim_start_matches("[^").trim_end_matches(']'))
+            .unwrap_or_default()
+    }
+
+    /// The contents of the footnote.
+    pub fn body(&self) -> Markup {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+}
+
 node! {
     /// A section heading: `= Introduction`.
     Heading
@@ -689,6 +768,15 @@ node! {
 }
 
 impl ListItem {
+    /// Whether the item is a checked task (`- [x]`), an unchecked task
+    /// (`- [ ]`), or no task at all.
+    pub fn checked(&self) -> Option<bool> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Checkbox)
+            .map(|node| node.children().any(|child| child.kind() == SyntaxKind::Text))
+    }
+
     /// The contents of the list item.
     pub fn body(&self) -> Markup {
         self.0.cast_first_match().unwrap_or_default()
@@ -1250,17 +1338,14 @@ impl Named {
 }
 
 node! {
-    /// A keyed pair: `"spacy key": true`.
+    /// A keyed pair: `"spacy key": true` or `1: true`.
     Keyed
 }
 
 impl Keyed {
-    /// The key: `"spacy key"`.
-    pub fn key(&self) -> Str {
-        self.0
-            .children()
-            .find_map(|node| node.cast::<Str>())
-            .unwrap_or_default()
+    /// The key: `"spacy key"` or `1`.
+    pub fn key(&self) -> Expr {
+        self.0.cast_first_match().unwrap_or_default()
     }
 
     /// The right-hand side of the pair: `true`.
@@ -1403,6 +1488,10 @@ pub enum BinOp {
     MulAssign,
     /// The divide-assign operator: `/=`.
     DivAssign,
+    /// The short-circuiting and-assign operator: `and=`.
+    AndAssign,
+    /// The short-circuiting or-assign operator: `or=`.
+    OrAssign,
 }
 
 impl BinOp {
@@ -1427,6 +1516,8 @@ impl BinOp {
             SyntaxKind::HyphEq => Self::SubAssign,
             SyntaxKind::StarEq => Self::MulAssign,
             SyntaxKind::SlashEq => Self::DivAssign,
+            SyntaxKind::AndEq => Self::AndAssign,
+            SyntaxKind::OrEq => Self::OrAssign,
             _ => return Option::None,
         })
     }
@@ -1453,6 +1544,8 @@ impl BinOp {
             Self::SubAssign => 1,
             Self::MulAssign => 1,
             Self::DivAssign => 1,
+            Self::AndAssign => 1,
+            Self::OrAssign => 1,
         }
     }
 
@@ -1478,6 +1571,8 @@ impl BinOp {
             Self::SubAssign => Assoc::Right,
             Self::MulAssign => Assoc::Right,
             Self::DivAssign => Assoc::Right,
+            Self::AndAssign => Assoc::Right,
+            Self::OrAssign => Assoc::Right,
         }
     }
 
@@ -1503,6 +1598,8 @@ impl BinOp {
             Self::SubAssign => "-=",
             Self::MulAssign => "*=",
             Self::DivAssign => "/=",
+            Self::AndAssign => "and=",
+            Self::OrAssign => "or=",
         }
     }
 }
@@ -1517,7 +1614,7 @@ pub enum Assoc {
 }
 
 node! {
-    /// A field access: `properties.age`.
+    /// A field access: `properties.age` or `properties?.age`.
     FieldAccess
 }
 
@@ -1527,6 +1624,12 @@ impl FieldAccess {
         self.0.cast_first_match().unwrap_or_default()
     }
 
+    /// Whether this is an optional field access (`?.`), which evaluates to
+    /// `none` instead of failing if the target is `none`.
+    pub fn optional(&self) -> bool {
+        self.0.children().any(|node| node.kind() == SyntaxKind::QuestionDot)
+    }
+
     /// The name of the field.
     pub fn field(&self) -> Ident {
         self.0.cast_last_match().unwrap_or_default()
@@ -1656,7 +1759,12 @@ pub enum Param {
     Pos(Pattern),
     /// A named parameter with a default value: `draw: false`.
     Named(Named),
+    /// A positional parameter with a type annotation: `n as int`.
+    Typed(TypedParam),
     /// An argument sink: `..args`.
+    ///
+    /// If a closure has two sinks, the first one only collects positional and
+    /// the second one only collects named arguments: `(..args, ..kwargs)`.
     Sink(Spread),
 }
 
@@ -1664,6 +1772,7 @@ impl AstNode for Param {
     fn from_untyped(node: &SyntaxNode) -> Option<Self> {
         match node.kind() {
             SyntaxKind::Named => node.cast().map(Self::Named),
+            SyntaxKind::TypedParam => node.cast().map(Self::Typed),
             SyntaxKind::Spread => node.cast().map(Self::Sink),
             _ => node.cast().map(Self::Pos),
         }
@@ -1673,11 +1782,29 @@ impl AstNode for Param {
         match self {
             Self::Pos(v) => v.as_untyped(),
             Self::Named(v) => v.as_untyped(),
+            Self::Typed(v) => v.as_untyped(),
             Self::Sink(v) => v.as_untyped(),
         }
     }
 }
 
+node! {
+    /// A parameter with a type annotation: `n as int`.
+    TypedParam
+}
+
+impl TypedParam {
+    /// The name of the parameter.
+    pub fn name(&self) -> Ident {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+
+    /// The type annotation of the parameter: `as int`.
+    pub fn annotation(&self) -> TypeAnnotation {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+}
+
 node! {
     /// A destructuring pattern: `x` or `(x, _, ..y)`.
     Destructuring
@@ -1815,6 +1942,11 @@ impl LetBinding {
         }
     }
 
+    /// The type annotation of the binding, if any: `as int`.
+    pub fn annotation(&self) -> Option<TypeAnnotation> {
+        self.0.cast_first_match()
+    }
+
     /// The expression the binding is initialized with.
     pub fn init(&self) -> Option<Expr> {
         match self.kind() {
@@ -1827,6 +1959,18 @@ impl LetBinding {
     }
 }
 
+node! {
+    /// A type annotation: `as int`.
+    TypeAnnotation
+}
+
+impl TypeAnnotation {
+    /// The name of the annotated type.
+    pub fn name(&self) -> Ident {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+}
+
 node! {
     /// An assignment expression `(x, y) = (1, 2)`.
     DestructAssignment
@@ -1917,7 +2061,7 @@ impl Conditional {
 }
 
 node! {
-    /// A while loop: `while x { y }`.
+    /// A while loop: `while x { y }` or `while x { y } else { z }`.
     WhileLoop
 }
 
@@ -1929,12 +2073,21 @@ impl WhileLoop {
 
     /// The expression to evaluate while the condition is true.
     pub fn body(&self) -> Expr {
-        self.0.cast_last_match().unwrap_or_default()
+        self.0
+            .children()
+            .filter_map(SyntaxNode::cast)
+            .nth(1)
+            .unwrap_or_default()
+    }
+
+    /// The expression to evaluate if the loop finished without `break`.
+    pub fn else_body(&self) -> Option<Expr> {
+        self.0.children().filter_map(SyntaxNode::cast).nth(2)
     }
 }
 
 node! {
-    /// A for loop: `for x in y { z }`.
+    /// A for loop: `for x in y { z }` or `for x in y { z } else { w }`.
     ForLoop
 }
 
@@ -1955,7 +2108,21 @@ impl ForLoop {
 
     /// The expression to evaluate for each iteration.
     pub fn body(&self) -> Expr {
-        self.0.cast_last_match().unwrap_or_default()
+        self.0
+            .children()
+            .skip_while(|&c| c.kind() != SyntaxKind::In)
+            .filter_map(SyntaxNode::cast)
+            .nth(1)
+            .unwrap_or_default()
+    }
+
+    /// The expression to evaluate if the loop finished without `break`.
+    pub fn else_body(&self) -> Option<Expr> {
+        self.0
+            .children()
+            .skip_while(|&c| c.kind() != SyntaxKind::In)
+            .filter_map(SyntaxNode::cast)
+            .nth(2)
     }
 }
 
@@ -1981,6 +2148,17 @@ impl ModuleImport {
             _ => Option::None,
         })
     }
+
+    /// The items excluded from a wildcard import: `* except (a, b)`.
+    pub fn exclusions(&self) -> Vec<Ident> {
+        self.0
+            .children()
+            .skip_while(|node| node.kind() != SyntaxKind::Except)
+            .find(|node| node.kind() == SyntaxKind::ImportItems)
+            .map_or_else(Vec::new, |node| {
+                node.children().filter_map(SyntaxNode::cast).collect()
+            })
+    }
 }
 
 /// The items that ought to be imported from a file.
@@ -1989,7 +2167,75 @@ pub enum Imports {
     /// All items in the scope of the file should be imported.
     Wildcard,
     /// The specified items from the file should be imported.
-    Items(Vec<Ident>),
+    Items(Vec<ImportItem>),
+}
+
+/// An item in an import list.
+#[derive(Debug, Clone, Hash)]
+pub enum ImportItem {
+    /// A plain identifier: `a`.
+    Simple(Ident),
+    /// A path to a nested item, optionally renamed: `a.b as c`.
+    Path(ImportPath),
+}
+
+impl ImportItem {
+    /// The name of the top-level item in the imported module.
+    pub fn original_name(&self) -> Ident {
+        match self {
+            Self::Simple(ident) => ident.clone(),
+            Self::Path(path) => path.segments().next().unwrap_or_default(),
+        }
+    }
+
+    /// The name under which the item is defined in the importing scope.
+    pub fn bound_name(&self) -> Ident {
+        match self {
+            Self::Simple(ident) => ident.clone(),
+            Self::Path(path) => {
+                path.new_name().or_else(|| path.segments().last()).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl AstNode for ImportItem {
+    fn from_untyped(node: &SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ImportPath => node.cast().map(Self::Path),
+            _ => node.cast().map(Self::Simple),
+        }
+    }
+
+    fn as_untyped(&self) -> &SyntaxNode {
+        match self {
+            Self::Simple(v) => v.as_untyped(),
+            Self::Path(v) => v.as_untyped(),
+        }
+    }
+}
+
+node! {
+    /// A path to a nested item in an import list: `a.b as c`.
+    ImportPath
+}
+
+impl ImportPath {
+    /// The identifiers along the path.
+    pub fn segments(&self) -> impl Iterator<Item = Ident> + '_ {
+        self.0
+            .children()
+            .take_while(|node| node.kind() != SyntaxKind::As)
+            .filter_map(SyntaxNode::cast)
+    }
+
+    /// The new name of the item, if it is renamed with `as`.
+    pub fn new_name(&self) -> Option<Ident> {
+        self.0
+            .children()
+            .skip_while(|node| node.kind() != SyntaxKind::As)
+            .find_map(SyntaxNode::cast)
+    }
 }
 
 node! {
@@ -97,6 +97,9 @@ impl FileId {
     }
 
     /// Resolve a file location relative to this file.
+    ///
+    /// Paths starting with a slash are relative to the root of the project or
+    /// package. Paths that would leave that root are rejected.
     pub fn join(self, path: &str) -> Result<Self, EcoString> {
         if self.is_detached() {
             Err("cannot access file system from here")?;
@@ -104,11 +107,23 @@ impl FileId {
 
         let package = self.package().cloned();
         let base = self.path();
-        Ok(if let Some(parent) = base.parent() {
-            Self::new(package, &parent.join(path))
-        } else {
-            Self::new(package, Path::new(path))
-        })
+        let joined = match base.parent() {
+            Some(parent) => parent.join(path),
+            None => PathBuf::from(path),
+        };
+
+        let escapes = normalize_path(&joined)
+            .components()
+            .any(|component| component == Component::ParentDir);
+
+        if escapes {
+            Err(match package {
+                Some(_) => "cannot access file outside of the package",
+                None => "cannot access file outside of the project root",
+            })?;
+        }
+
+        Ok(Self::new(package, &joined))
     }
 
     /// Construct from a raw number.
@@ -36,6 +36,10 @@ pub enum SyntaxKind {
     Ref,
     /// Introduces a reference: `@target`.
     RefMarker,
+    /// A reference to a footnote: `[^note]`.
+    FootnoteRef,
+    /// The definition of a footnote: `[^note]: Details`.
+    FootnoteDef,
     /// A section heading: `= Introduction`.
     Heading,
     /// Introduces a section heading: `=`, `==`, ...
@@ -44,6 +48,8 @@ pub enum SyntaxKind {
     ListItem,
     /// Introduces a list item: `-`.
     ListMarker,
+    /// Marks a list item as a task: `[ ]`, `[x]`.
+    Checkbox,
     /// An item in an enumeration (numbered list): `+ ...` or `1. ...`.
     EnumItem,
     /// Introduces an enumeration item: `+`, `1.`.
@@ -114,6 +120,8 @@ pub enum SyntaxKind {
     Prime,
     /// The field access and method call operator: `.`.
     Dot,
+    /// The optional field access operator: `?.`.
+    QuestionDot,
     /// The assignment operator: `=`.
     Eq,
     /// The equality operator: `==`.
@@ -136,6 +144,10 @@ pub enum SyntaxKind {
     StarEq,
     /// The divide-assign operator: `/=`.
     SlashEq,
+    /// The and-assign operator: `and=`.
+    AndEq,
+    /// The or-assign operator: `or=`.
+    OrEq,
     /// The spread operator: `..`.
     Dots,
     /// An arrow between a closure's parameters and body: `=>`.
@@ -181,6 +193,8 @@ pub enum SyntaxKind {
     Include,
     /// The `as` keyword.
     As,
+    /// The contextual `except` keyword in a wildcard import.
+    Except,
 
     /// Code.
     Code,
@@ -226,8 +240,12 @@ pub enum SyntaxKind {
     Closure,
     /// A closure's parameters: `(x, y)`.
     Params,
+    /// A closure parameter with a type annotation: `n as int`.
+    TypedParam,
     /// A let binding: `let x = 1`.
     LetBinding,
+    /// A type annotation: `as int`.
+    TypeAnnotation,
     /// A set rule: `set text(...)`.
     SetRule,
     /// A show rule: `show heading: it => emph(it.body)`.
@@ -242,6 +260,8 @@ pub enum SyntaxKind {
     ModuleImport,
     /// Items to import from a module: `a, b, c`.
     ImportItems,
+    /// A path to a nested item in an import list: `a.b as c`.
+    ImportPath,
     /// A module include: `include "chapter1.typ"`.
     ModuleInclude,
     /// A break from a loop: `break`.
@@ -331,6 +351,7 @@ impl SyntaxKind {
                 | Self::Import
                 | Self::Include
                 | Self::As
+                | Self::Except
         )
     }
 
@@ -366,10 +387,13 @@ impl SyntaxKind {
             Self::Label => "label",
             Self::Ref => "reference",
             Self::RefMarker => "reference marker",
+            Self::FootnoteRef => "footnote reference",
+            Self::FootnoteDef => "footnote definition",
             Self::Heading => "heading",
             Self::HeadingMarker => "heading marker",
             Self::ListItem => "list item",
             Self::ListMarker => "list marker",
+            Self::Checkbox => "checkbox",
             Self::EnumItem => "enum item",
             Self::EnumMarker => "enum marker",
             Self::TermItem => "term list item",
@@ -402,6 +426,7 @@ impl SyntaxKind {
             Self::Hat => "hat",
             Self::Prime => "prime",
             Self::Dot => "dot",
+            Self::QuestionDot => "optional field access operator",
             Self::Eq => "equals sign",
             Self::EqEq => "equality operator",
             Self::ExclEq => "inequality operator",
@@ -413,6 +438,8 @@ impl SyntaxKind {
             Self::HyphEq => "subtract-assign operator",
             Self::StarEq => "multiply-assign operator",
             Self::SlashEq => "divide-assign operator",
+            Self::AndEq => "and-assign operator",
+            Self::OrEq => "or-assign operator",
             Self::Dots => "dots",
             Self::Arrow => "arrow",
             Self::Root => "root",
@@ -435,6 +462,7 @@ impl SyntaxKind {
             Self::Import => "keyword `import`",
             Self::Include => "keyword `include`",
             Self::As => "keyword `as`",
+            Self::Except => "keyword `except`",
             Self::Code => "code",
             Self::Ident => "identifier",
             Self::Bool => "boolean",
@@ -457,7 +485,9 @@ impl SyntaxKind {
             Self::Spread => "spread",
             Self::Closure => "closure",
             Self::Params => "closure parameters",
+            Self::TypedParam => "typed parameter",
             Self::LetBinding => "`let` expression",
+            Self::TypeAnnotation => "type annotation",
             Self::SetRule => "`set` expression",
             Self::ShowRule => "`show` expression",
             Self::Conditional => "`if` expression",
@@ -465,6 +495,7 @@ impl SyntaxKind {
             Self::ForLoop => "for-loop expression",
             Self::ModuleImport => "`import` expression",
             Self::ImportItems => "import items",
+            Self::ImportPath => "import path",
             Self::ModuleInclude => "`include` expression",
             Self::LoopBreak => "`break` expression",
             Self::LoopContinue => "`continue` expression",
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use ecow::{eco_format, EcoString};
 use unicode_ident::{is_xid_continue, is_xid_start};
 use unicode_segmentation::UnicodeSegmentation;
@@ -21,7 +23,7 @@ pub(super) struct Lexer<'s> {
 
 /// What kind of tokens to emit.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub(super) enum LexMode {
+pub enum LexMode {
     /// Text and markup.
     Markup,
     /// Math atoms, operators, etc.
@@ -175,6 +177,7 @@ impl Lexer<'_> {
             '_' if !self.in_word() => SyntaxKind::Underscore,
 
             '#' => SyntaxKind::Hashtag,
+            '[' if self.s.at('^') => self.footnote_ref(start),
             '[' => SyntaxKind::LeftBracket,
             ']' => SyntaxKind::RightBracket,
             '\'' => SyntaxKind::SmartQuote,
@@ -314,6 +317,17 @@ impl Lexer<'_> {
         SyntaxKind::RefMarker
     }
 
+    fn footnote_ref(&mut self, start: usize) -> SyntaxKind {
+        self.s.expect('^');
+        let name = self.s.eat_while(is_id_continue);
+        if name.is_empty() || !self.s.eat_if(']') {
+            self.s.jump(start + 1);
+            return SyntaxKind::LeftBracket;
+        }
+
+        SyntaxKind::FootnoteRef
+    }
+
     fn label(&mut self) -> SyntaxKind {
         let label = self.s.eat_while(|c| is_id_continue(c) || matches!(c, ':' | '.'));
         if label.is_empty() {
@@ -484,6 +498,7 @@ impl Lexer<'_> {
             '*' if self.s.eat_if('=') => SyntaxKind::StarEq,
             '/' if self.s.eat_if('=') => SyntaxKind::SlashEq,
             '.' if self.s.eat_if('.') => SyntaxKind::Dots,
+            '?' if self.s.eat_if('.') => SyntaxKind::QuestionDot,
             '=' if self.s.eat_if('>') => SyntaxKind::Arrow,
 
             '{' => SyntaxKind::LeftBrace,
@@ -518,6 +533,19 @@ impl Lexer<'_> {
         let prev = self.s.get(0..start);
         if !prev.ends_with(['.', '@']) || prev.ends_with("..") {
             if let Some(keyword) = keyword(ident) {
+                if self.s.at('=') && !self.s.at("==") {
+                    match keyword {
+                        SyntaxKind::And => {
+                            self.s.eat();
+                            return SyntaxKind::AndEq;
+                        }
+                        SyntaxKind::Or => {
+                            self.s.eat();
+                            return SyntaxKind::OrEq;
+                        }
+                        _ => {}
+                    }
+                }
                 return keyword;
             }
         }
@@ -649,6 +677,26 @@ fn keyword(ident: &str) -> Option<SyntaxKind> {
     })
 }
 
+/// Split up a string of source code into a flat stream of tokens.
+///
+/// Yields the kind and byte range of each token. In contrast to the parser,
+/// the tokenizer does not switch modes on its own: Embedded code in markup,
+/// for example, is lexed with the markup rules. Trivia like spaces and
+/// comments are included and the ranges cover the whole text without gaps.
+pub fn tokenize(
+    text: &str,
+    mode: LexMode,
+) -> impl Iterator<Item = (SyntaxKind, Range<usize>)> + '_ {
+    let mut lexer = Lexer::new(text, mode);
+    std::iter::from_fn(move || {
+        let start = lexer.cursor();
+        match lexer.next() {
+            SyntaxKind::Eof => None,
+            kind => Some((kind, start..lexer.cursor())),
+        }
+    })
+}
+
 /// Whether a character is interpreted as a newline by Typst.
 #[inline]
 pub fn is_newline(character: char) -> bool {
@@ -737,3 +785,56 @@ fn is_math_id_start(c: char) -> bool {
 fn is_math_id_continue(c: char) -> bool {
     is_xid_continue(c) && c != '_'
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn test(text: &str, mode: LexMode, expected: &[(SyntaxKind, &str)]) {
+        let found: Vec<_> = tokenize(text, mode)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_tokenize() {
+        use SyntaxKind::*;
+        test("", LexMode::Markup, &[]);
+        test(
+            "= Hi *there*",
+            LexMode::Markup,
+            &[
+                (HeadingMarker, "="),
+                (Space, " "),
+                (Text, "Hi"),
+                (Space, " "),
+                (Star, "*"),
+                (Text, "there"),
+                (Star, "*"),
+            ],
+        );
+        test(
+            "let x = 1 // hi",
+            LexMode::Code,
+            &[
+                (Let, "let"),
+                (Space, " "),
+                (Ident, "x"),
+                (Space, " "),
+                (Eq, "="),
+                (Space, " "),
+                (Int, "1"),
+                (Space, " "),
+                (LineComment, "// hi"),
+            ],
+        );
+        test("ab^2", LexMode::Math, &[(MathIdent, "ab"), (Hat, "^"), (Text, "2")]);
+        test(
+            "(a), (c)",
+            LexMode::Markup,
+            &[(Text, "(a),"), (Space, " "), (Text, "(c)")],
+        );
+    }
+}
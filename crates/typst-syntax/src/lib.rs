@@ -13,11 +13,13 @@ mod span;
 
 pub use self::file::{FileId, PackageSpec, PackageVersion};
 pub use self::kind::SyntaxKind;
-pub use self::lexer::{is_id_continue, is_id_start, is_ident, is_newline};
+pub use self::lexer::{
+    is_id_continue, is_id_start, is_ident, is_newline, tokenize, LexMode,
+};
 pub use self::node::{LinkedChildren, LinkedNode, SyntaxError, SyntaxNode};
 pub use self::parser::{parse, parse_code, parse_math};
 pub use self::source::Source;
 pub use self::span::{Span, Spanned};
 
-use self::lexer::{split_newlines, LexMode, Lexer};
+use self::lexer::{split_newlines, Lexer};
 use self::parser::{reparse_block, reparse_markup};
@@ -1,4 +1,4 @@
-use std::fmt::{self, Debug, Display, Formatter};
+use std::fmt::{self, Debug, Display, Formatter, Write};
 use std::ops::{Deref, Range};
 use std::rc::Rc;
 use std::sync::Arc;
@@ -134,6 +134,13 @@ impl SyntaxNode {
     }
 
     /// The error messages for this node and its descendants.
+    ///
+    /// Each error's span points to the error node it was produced for. The
+    /// node covers exactly the offending source text, or is empty if
+    /// something was expected but missing, so [`Source::range`] yields the
+    /// precise byte range to mark.
+    ///
+    /// [`Source::range`]: crate::Source::range
     pub fn errors(&self) -> Vec<SyntaxError> {
         if !self.erroneous() {
             return vec![];
@@ -164,6 +171,51 @@ impl SyntaxNode {
             Repr::Error(node) => Arc::make_mut(node).error.span = span,
         }
     }
+
+    /// Render the node and its descendants as an indented S-expression.
+    ///
+    /// Each node is printed with its kind, leaves additionally with their
+    /// source text and error nodes with their text, message and hints. If
+    /// `ranges` is true, each node also shows its byte range in the source.
+    pub fn pretty(&self, ranges: bool) -> String {
+        let mut buf = String::new();
+        self.pretty_into(&mut buf, 0, 0, ranges);
+        buf
+    }
+
+    /// Write the S-expression for this node, indented by `depth` levels and
+    /// starting at byte `offset`, into `buf`.
+    fn pretty_into(&self, buf: &mut String, depth: usize, offset: usize, ranges: bool) {
+        for _ in 0..depth {
+            buf.push_str("  ");
+        }
+
+        write!(buf, "({:?}", self.kind()).unwrap();
+        if ranges {
+            write!(buf, " {}..{}", offset, offset + self.len()).unwrap();
+        }
+
+        match &self.0 {
+            Repr::Leaf(leaf) => write!(buf, " {:?}", leaf.text).unwrap(),
+            Repr::Inner(inner) => {
+                let mut offset = offset;
+                for child in &inner.children {
+                    buf.push('\n');
+                    child.pretty_into(buf, depth + 1, offset, ranges);
+                    offset += child.len();
+                }
+            }
+            Repr::Error(node) => {
+                write!(buf, " {:?} (message {:?})", node.text, node.error.message)
+                    .unwrap();
+                for hint in &node.error.hints {
+                    write!(buf, " (hint {hint:?})").unwrap();
+                }
+            }
+        }
+
+        buf.push(')');
+    }
 }
 
 impl SyntaxNode {
@@ -909,4 +961,55 @@ mod tests {
         assert_eq!(leaf.text(), " ");
         assert_eq!(next.text(), "10");
     }
+
+    #[test]
+    fn test_pretty() {
+        let source = Source::detached("*a* #x");
+        assert_eq!(
+            source.root().pretty(false),
+            [
+                "(Markup",
+                "  (Strong",
+                "    (Star \"*\")",
+                "    (Markup",
+                "      (Text \"a\"))",
+                "    (Star \"*\"))",
+                "  (Space \" \")",
+                "  (Hashtag \"#\")",
+                "  (Ident \"x\"))",
+            ]
+            .join("\n"),
+        );
+
+        let source = Source::detached("*a");
+        assert_eq!(
+            source.root().pretty(true),
+            [
+                "(Markup 0..2",
+                "  (Strong 0..2",
+                "    (Error 0..1 \"*\" (message \"unclosed delimiter\"))",
+                "    (Markup 1..2",
+                "      (Text 1..2 \"a\"))))",
+            ]
+            .join("\n"),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let source = Source::detached("*a _b");
+        let errors: Vec<_> = source
+            .root()
+            .errors()
+            .into_iter()
+            .map(|error| (source.range(error.span), error.message))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (0..1, EcoString::from("unclosed delimiter")),
+                (3..4, EcoString::from("unclosed delimiter")),
+            ]
+        );
+    }
 }
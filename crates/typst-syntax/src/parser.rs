@@ -6,6 +6,13 @@ use unicode_math_class::MathClass;
 
 use super::{ast, is_newline, LexMode, Lexer, SyntaxKind, SyntaxNode};
 
+/// The number of columns between two tab stops, used to determine the
+/// indentation of lines indented with tabs.
+///
+/// This is deliberately not configurable: The structure of a document must not
+/// depend on the settings of the editor or compiler that processes it.
+const TAB_WIDTH: usize = 4;
+
 /// Parse a source file.
 #[tracing::instrument(skip_all)]
 pub fn parse(text: &str) -> SyntaxNode {
@@ -125,6 +132,12 @@ fn markup_expr(p: &mut Parser, at_start: &mut bool) {
         SyntaxKind::EnumMarker if *at_start => enum_item(p),
         SyntaxKind::TermMarker if *at_start => term_item(p),
         SyntaxKind::RefMarker => reference(p),
+        SyntaxKind::FootnoteRef
+            if *at_start && p.lexer.clone().next() == SyntaxKind::Colon =>
+        {
+            footnote_def(p)
+        }
+        SyntaxKind::FootnoteRef => p.eat(),
         SyntaxKind::Dollar => equation(p),
 
         SyntaxKind::LeftBracket
@@ -182,10 +195,31 @@ fn list_item(p: &mut Parser) {
     let min_indent = p.column(p.current_start()) + 1;
     p.assert(SyntaxKind::ListMarker);
     whitespace_line(p);
+    if checkbox(p) {
+        whitespace_line(p);
+    }
     markup(p, false, min_indent, |p| p.at(SyntaxKind::RightBracket));
     p.wrap(m, SyntaxKind::ListItem);
 }
 
+/// Parse a task checkbox. It must be followed by whitespace or the end of the
+/// text, so an item consisting of just a checkbox is a task with an empty body.
+fn checkbox(p: &mut Parser) -> bool {
+    let rest = &p.text[p.current_start()..];
+    if !["[ ]", "[x]", "[X]"].iter().any(|prefix| rest.starts_with(prefix))
+        || !rest[3..].chars().next().map_or(true, char::is_whitespace)
+    {
+        return false;
+    }
+
+    let m = p.marker();
+    p.assert(SyntaxKind::LeftBracket);
+    p.eat();
+    p.assert(SyntaxKind::RightBracket);
+    p.wrap(m, SyntaxKind::Checkbox);
+    true
+}
+
 fn enum_item(p: &mut Parser) {
     let m = p.marker();
     let min_indent = p.column(p.current_start()) + 1;
@@ -209,6 +243,16 @@ fn term_item(p: &mut Parser) {
     p.wrap(m, SyntaxKind::TermItem);
 }
 
+fn footnote_def(p: &mut Parser) {
+    let m = p.marker();
+    let min_indent = p.column(p.current_start()) + 1;
+    p.assert(SyntaxKind::FootnoteRef);
+    p.assert(SyntaxKind::Colon);
+    whitespace_line(p);
+    markup(p, false, min_indent, |p| p.at(SyntaxKind::RightBracket));
+    p.wrap(m, SyntaxKind::FootnoteDef);
+}
+
 fn reference(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::RefMarker);
@@ -662,14 +706,15 @@ fn code_expr_prec(
             continue;
         }
 
-        let at_field_or_method =
-            p.directly_at(SyntaxKind::Dot) && p.lexer.clone().next() == SyntaxKind::Ident;
+        let at_field_or_method = (p.directly_at(SyntaxKind::Dot)
+            || p.directly_at(SyntaxKind::QuestionDot))
+            && p.lexer.clone().next() == SyntaxKind::Ident;
 
         if atomic && !at_field_or_method {
             break;
         }
 
-        if p.eat_if(SyntaxKind::Dot) {
+        if p.eat_if(SyntaxKind::Dot) || p.eat_if(SyntaxKind::QuestionDot) {
             p.expect(SyntaxKind::Ident);
             p.wrap(m, SyntaxKind::FieldAccess);
             continue;
@@ -864,6 +909,7 @@ fn collection(p: &mut Parser, keyed: bool) -> SyntaxKind {
     p.stop_at_newline(false);
 
     let m = p.marker();
+    let open = p.current_start();
     p.assert(SyntaxKind::LeftParen);
 
     let mut count = 0;
@@ -904,6 +950,13 @@ fn collection(p: &mut Parser, keyed: bool) -> SyntaxKind {
             break;
         }
 
+        // A missing comma before a line that isn't indented further than
+        // the opening parenthesis most likely means that the group was left
+        // unclosed while editing.
+        if !p.at(SyntaxKind::Comma) && p.at_dedent(open) {
+            break;
+        }
+
         if p.expect(SyntaxKind::Comma) {
             parenthesized = false;
         }
@@ -946,6 +999,13 @@ fn item(p: &mut Parser, keyed: bool) -> SyntaxKind {
 
     code_expr_or_pattern(p);
 
+    if p.at(SyntaxKind::As) && p.node(m).map(SyntaxNode::kind) == Some(SyntaxKind::Ident)
+    {
+        type_annotation(p);
+        p.wrap(m, SyntaxKind::TypedParam);
+        return SyntaxKind::TypedParam;
+    }
+
     if !p.eat_if(SyntaxKind::Colon) {
         return SyntaxKind::Int;
     }
@@ -956,7 +1016,7 @@ fn item(p: &mut Parser, keyed: bool) -> SyntaxKind {
 
     let kind = match p.node(m).map(SyntaxNode::kind) {
         Some(SyntaxKind::Ident) => SyntaxKind::Named,
-        Some(SyntaxKind::Str) if keyed => SyntaxKind::Keyed,
+        Some(SyntaxKind::Str | SyntaxKind::Int) if keyed => SyntaxKind::Keyed,
         _ => {
             for child in p.post_process(m) {
                 if child.kind() == SyntaxKind::Colon {
@@ -965,7 +1025,7 @@ fn item(p: &mut Parser, keyed: bool) -> SyntaxKind {
 
                 let mut message = EcoString::from("expected identifier");
                 if keyed {
-                    message.push_str(" or string");
+                    message.push_str(", string, or integer");
                 }
                 message.push_str(", found ");
                 message.push_str(child.kind().name());
@@ -1038,6 +1098,8 @@ fn let_binding(p: &mut Parser) {
                 collection(p, false);
                 validate_params_at(p, m3);
                 p.wrap(m3, SyntaxKind::Params);
+            } else if p.at(SyntaxKind::As) {
+                type_annotation(p);
             }
         }
         PatternKind::Placeholder => {}
@@ -1056,6 +1118,13 @@ fn let_binding(p: &mut Parser) {
     p.wrap(m, SyntaxKind::LetBinding);
 }
 
+fn type_annotation(p: &mut Parser) {
+    let m = p.marker();
+    p.assert(SyntaxKind::As);
+    p.expect(SyntaxKind::Ident);
+    p.wrap(m, SyntaxKind::TypeAnnotation);
+}
+
 fn set_rule(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Set);
@@ -1112,6 +1181,9 @@ fn while_loop(p: &mut Parser) {
     p.assert(SyntaxKind::While);
     code_expr(p);
     block(p);
+    if p.eat_if(SyntaxKind::Else) {
+        block(p);
+    }
     p.wrap(m, SyntaxKind::WhileLoop);
 }
 
@@ -1131,6 +1203,9 @@ fn for_loop(p: &mut Parser) {
     }
     code_expr(p);
     block(p);
+    if p.eat_if(SyntaxKind::Else) {
+        block(p);
+    }
     p.wrap(m, SyntaxKind::ForLoop);
 }
 
@@ -1138,8 +1213,15 @@ fn module_import(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Import);
     code_expr(p);
-    if p.eat_if(SyntaxKind::Colon) && !p.eat_if(SyntaxKind::Star) {
-        import_items(p);
+    if p.eat_if(SyntaxKind::Colon) {
+        if p.eat_if(SyntaxKind::Star) {
+            if p.at(SyntaxKind::Ident) && p.current_text() == "except" {
+                p.convert(SyntaxKind::Except);
+                import_exclusions(p);
+            }
+        } else {
+            import_items(p);
+        }
     }
     p.wrap(m, SyntaxKind::ModuleImport);
 }
@@ -1147,8 +1229,17 @@ fn module_import(p: &mut Parser) {
 fn import_items(p: &mut Parser) {
     let m = p.marker();
     while !p.eof() && !p.at(SyntaxKind::Semicolon) {
+        let item = p.marker();
         if !p.eat_if(SyntaxKind::Ident) {
             p.unexpected();
+        } else if p.at(SyntaxKind::Dot) || p.at(SyntaxKind::As) {
+            while p.eat_if(SyntaxKind::Dot) {
+                p.expect(SyntaxKind::Ident);
+            }
+            if p.eat_if(SyntaxKind::As) {
+                p.expect(SyntaxKind::Ident);
+            }
+            p.wrap(item, SyntaxKind::ImportPath);
         }
         if p.current().is_terminator() {
             break;
@@ -1158,6 +1249,29 @@ fn import_items(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ImportItems);
 }
 
+fn import_exclusions(p: &mut Parser) {
+    if !p.at(SyntaxKind::LeftParen) {
+        p.expected("opening paren");
+        return;
+    }
+
+    p.stop_at_newline(false);
+    let m = p.marker();
+    p.assert(SyntaxKind::LeftParen);
+    while !p.current().is_terminator() {
+        if !p.eat_if(SyntaxKind::Ident) {
+            p.unexpected();
+        }
+        if p.current().is_terminator() {
+            break;
+        }
+        p.expect(SyntaxKind::Comma);
+    }
+    p.expect_closing_delimiter(m, SyntaxKind::RightParen);
+    p.unstop();
+    p.wrap(m, SyntaxKind::ImportItems);
+}
+
 fn module_include(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Include);
@@ -1192,7 +1306,7 @@ fn validate_parenthesized_at(p: &mut Parser, m: Marker) {
         match kind {
             SyntaxKind::Array => validate_array(child.children_mut().iter_mut()),
             SyntaxKind::Dict => validate_dict(child.children_mut().iter_mut()),
-            SyntaxKind::Underscore => {
+            SyntaxKind::Underscore | SyntaxKind::TypedParam => {
                 child.convert_to_error(eco_format!(
                     "expected expression, found {}",
                     kind.name()
@@ -1213,7 +1327,10 @@ fn validate_array<'a>(children: impl Iterator<Item = &'a mut SyntaxNode>) {
         match kind {
             SyntaxKind::Array => validate_array(child.children_mut().iter_mut()),
             SyntaxKind::Dict => validate_dict(child.children_mut().iter_mut()),
-            SyntaxKind::Named | SyntaxKind::Keyed | SyntaxKind::Underscore => {
+            SyntaxKind::Named
+            | SyntaxKind::Keyed
+            | SyntaxKind::Underscore
+            | SyntaxKind::TypedParam => {
                 child.convert_to_error(eco_format!(
                     "expected expression, found {}",
                     kind.name()
@@ -1234,9 +1351,12 @@ fn validate_dict<'a>(children: impl Iterator<Item = &'a mut SyntaxNode>) {
         match child.kind() {
             SyntaxKind::Named | SyntaxKind::Keyed => {
                 let Some(first) = child.children_mut().first_mut() else { continue };
-                let key = match first.cast::<ast::Str>() {
-                    Some(str) => str.get(),
-                    None => first.text().clone(),
+                let key = if let Some(str) = first.cast::<ast::Str>() {
+                    str.get()
+                } else if let Some(int) = first.cast::<ast::Int>() {
+                    eco_format!("{}", int.get())
+                } else {
+                    first.text().clone()
                 };
 
                 if !used.insert(key.clone()) {
@@ -1261,7 +1381,7 @@ fn validate_dict<'a>(children: impl Iterator<Item = &'a mut SyntaxNode>) {
 }
 
 fn validate_params_at(p: &mut Parser, m: Marker) {
-    let mut used_spread = false;
+    let mut sinks = 0;
     let mut used = HashSet::new();
     for child in p.post_process(m) {
         match child.kind() {
@@ -1273,7 +1393,7 @@ fn validate_params_at(p: &mut Parser, m: Marker) {
                     ));
                 }
             }
-            SyntaxKind::Named => {
+            SyntaxKind::Named | SyntaxKind::TypedParam => {
                 let Some(within) = child.children_mut().first_mut() else { return };
                 if !used.insert(within.text().clone()) {
                     within.convert_to_error(eco_format!(
@@ -1285,11 +1405,13 @@ fn validate_params_at(p: &mut Parser, m: Marker) {
             }
             SyntaxKind::Spread => {
                 let Some(within) = child.children_mut().last_mut() else { continue };
-                if used_spread {
-                    child.convert_to_error("only one argument sink is allowed");
+                if sinks == 2 {
+                    child.convert_to_error(
+                        "only one positional and one named argument sink are allowed",
+                    );
                     continue;
                 }
-                used_spread = true;
+                sinks += 1;
                 if within.kind() == SyntaxKind::Dots {
                     continue;
                 } else if within.kind() != SyntaxKind::Ident {
@@ -1340,6 +1462,8 @@ fn validate_args_at(p: &mut Parser, m: Marker) {
             }
         } else if child.kind() == SyntaxKind::Underscore {
             child.convert_to_error("unexpected underscore");
+        } else if child.kind() == SyntaxKind::TypedParam {
+            child.convert_to_error("type annotations are only allowed on parameters");
         }
     }
 }
@@ -1412,6 +1536,9 @@ fn validate_pattern<'a>(
                     }
                 }
             }
+            SyntaxKind::TypedParam => {
+                child.convert_to_error("type annotations are only allowed on parameters");
+            }
             SyntaxKind::LeftParen
             | SyntaxKind::RightParen
             | SyntaxKind::Comma
@@ -1534,8 +1661,43 @@ impl<'s> Parser<'s> {
         self.lexer.newline()
     }
 
+    /// The column of the offset `at` in its line. A tab advances the column
+    /// to the next multiple of [`TAB_WIDTH`].
     fn column(&self, at: usize) -> usize {
-        self.text[..at].chars().rev().take_while(|&c| !is_newline(c)).count()
+        self.text[self.line_start(at)..at].chars().fold(0, |column, c| {
+            if c == '\t' {
+                (column / TAB_WIDTH + 1) * TAB_WIDTH
+            } else {
+                column + 1
+            }
+        })
+    }
+
+    /// The byte offset at which the line containing the offset `at` starts.
+    fn line_start(&self, at: usize) -> usize {
+        self.text[..at]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| is_newline(c))
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+
+    /// Whether the current token starts a line that is not indented further
+    /// than the line containing the offset `open`.
+    fn at_dedent(&self, open: usize) -> bool {
+        let between = &self.text[self.prev_end..self.current_start];
+        if !between.chars().any(is_newline) {
+            return false;
+        }
+
+        let start = self.line_start(open);
+        let line = &self.text[start..];
+        let indent = line.len()
+            - line
+                .trim_start_matches(|c: char| c.is_whitespace() && !is_newline(c))
+                .len();
+
+        self.column(self.current_start) <= self.column(start + indent)
     }
 
     fn marker(&self) -> Marker {
@@ -1672,10 +1834,21 @@ impl<'s> Parser<'s> {
         at
     }
 
-    /// Produce an error that the given `thing` was expected.
+    /// Produce an error that the given `thing` was expected, naming the token
+    /// found instead if there is one.
     fn expected(&mut self, thing: &str) {
-        if !self.after_error() {
+        if self.after_error() {
+            return;
+        }
+
+        if self.eof() || self.current.is_trivia() || self.current.is_error() {
             self.expected_at(self.before_trivia(), thing);
+        } else {
+            let found = self.current.name();
+            self.expected_at(
+                self.before_trivia(),
+                &eco_format!("{thing}, found {found}"),
+            );
         }
     }
 
@@ -93,12 +93,26 @@ fn try_reparse(
     }
 
     // Try to reparse a range of markup expressions within markup. This is only
-    // possible if the markup is top-level or contained in a block, not if it is
-    // contained in things like headings or lists because too much can go wrong
-    // with indent and line breaks.
+    // possible if the markup is top-level, contained in a block or the body of
+    // a heading, not if it is contained in things like lists because too much
+    // can go wrong with indent and line breaks.
     if overlap.is_empty()
         || node.kind() != SyntaxKind::Markup
-        || !matches!(parent_kind, None | Some(SyntaxKind::ContentBlock))
+        || !matches!(
+            parent_kind,
+            None | Some(SyntaxKind::ContentBlock | SyntaxKind::Heading)
+        )
+    {
+        return None;
+    }
+
+    // A heading's body ends at the next line break, so we can only stay
+    // within it if the edit didn't introduce one.
+    let in_heading = parent_kind == Some(SyntaxKind::Heading);
+    if in_heading
+        && text[replaced.start..replaced.start + replacement_len]
+            .chars()
+            .any(is_newline)
     {
         return None;
     }
@@ -129,10 +143,10 @@ fn try_reparse(
         }
 
         // Synthesize what `at_start` and `nesting` would be at the start of the
-        // reparse.
+        // reparse. A heading's body is never at the start of a line.
         let mut prefix_len = 0;
         let mut nesting = 0;
-        let mut at_start = true;
+        let mut at_start = !in_heading;
         for child in &children[..start] {
             prefix_len += child.len();
             next_at_start(child, &mut at_start);
@@ -155,20 +169,19 @@ fn try_reparse(
         let new_range = shifted..shifted + new_len;
         let at_end = end == children.len();
 
-        // Stop parsing early if this kind is encountered.
-        let stop_kind = match parent_kind {
-            Some(_) => SyntaxKind::RightBracket,
-            None => SyntaxKind::Eof,
+        // Stop parsing early if this kind is encountered. Headings also end at
+        // labels.
+        let stop = |kind| match parent_kind {
+            Some(SyntaxKind::Heading) => {
+                kind == SyntaxKind::RightBracket || kind == SyntaxKind::Label
+            }
+            Some(_) => kind == SyntaxKind::RightBracket,
+            None => kind == SyntaxKind::Eof,
         };
 
         // Reparse!
-        let reparsed = reparse_markup(
-            text,
-            new_range.clone(),
-            &mut at_start,
-            &mut nesting,
-            |kind| kind == stop_kind,
-        );
+        let reparsed =
+            reparse_markup(text, new_range.clone(), &mut at_start, &mut nesting, stop);
 
         if let Some(newborns) = reparsed {
             // If more children follow, at_start must match its previous value.
@@ -289,7 +302,7 @@ mod tests {
         test("::1\n2. a\n3", 7..7, "4", true);
         test("* #{1+2} *", 6..7, "3", true);
         test("#{(0, 1, 2)}", 6..7, "11pt", true);
-        test("\n= A heading", 4..4, "n evocative", false);
+        test("\n= A heading", 4..4, "n evocative", true);
         test("#call() abc~d", 7..7, "[]", true);
         test("a your thing a", 6..7, "a", false);
         test("#grid(columns: (auto, 1fr, 40%))", 16..20, "4pt", false);
@@ -301,6 +314,18 @@ mod tests {
         test(r#"a ```typst hello```"#, 16..17, "", false);
     }
 
+    #[test]
+    fn test_reparse_heading() {
+        test("a\n= Heading here\nb", 10..10, " text", true);
+        test("a\n= Heading *here*\nb", 13..13, "x", true);
+        test("a\n= Heading here\nb", 2..2, "=", true);
+        test("a\n== Heading here\nb", 2..3, "", true);
+        test("a\n= Heading here\nb", 10..10, "\nc", true);
+        test("a\n= Heading here <l>\nb", 10..10, " <k>", true);
+        test("#[= Heading here] b", 11..11, "]", true);
+        test("= A heading", 4..4, "\n", false);
+    }
+
     #[test]
     fn test_reparse_block() {
         test("Hello #{ x + 1 }!", 9..10, "abc", true);
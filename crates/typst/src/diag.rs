@@ -98,6 +98,10 @@ pub struct SourceDiagnostic {
     /// Additonal hints to the user, indicating how this problem could be avoided
     /// or worked around.
     pub hints: Vec<EcoString>,
+    /// Whether the error must abort the evaluation, like when a resource limit
+    /// is exceeded. Evaluation never recovers from such an error and it cannot
+    /// be caught.
+    pub fatal: bool,
 }
 
 /// The severity of a [`SourceDiagnostic`].
@@ -118,9 +122,15 @@ impl SourceDiagnostic {
             trace: vec![],
             message: message.into(),
             hints: vec![],
+            fatal: false,
         }
     }
 
+    /// Create a new, bare error that aborts the evaluation.
+    pub fn fatal(span: Span, message: impl Into<EcoString>) -> Self {
+        Self { fatal: true, ..Self::error(span, message) }
+    }
+
     /// Create a new, bare warning.
     pub fn warning(span: Span, message: impl Into<EcoString>) -> Self {
         Self {
@@ -129,6 +139,7 @@ impl SourceDiagnostic {
             trace: vec![],
             message: message.into(),
             hints: vec![],
+            fatal: false,
         }
     }
 
@@ -153,10 +164,26 @@ impl From<SyntaxError> for SourceDiagnostic {
             message: error.message,
             trace: vec![],
             hints: error.hints,
+            fatal: false,
         }
     }
 }
 
+/// Sort diagnostics into a deterministic order.
+///
+/// Diagnostics are grouped by the file their span points into, ordered by the
+/// files' ids. Within a file, they are ordered by the position of their span,
+/// which follows from how spans are numbered: A parent node comes before its
+/// children and a node comes before its right siblings. Diagnostics with
+/// detached spans come last. The sort is stable, so diagnostics with the same
+/// span keep their relative order.
+pub fn sort_diagnostics(diagnostics: &mut [SourceDiagnostic]) {
+    diagnostics.sort_by_key(|diagnostic| {
+        let span = diagnostic.span;
+        (span.is_detached(), span.id(), span.number())
+    });
+}
+
 /// A part of a diagnostic's [trace](SourceDiagnostic::trace).
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Tracepoint {
@@ -3,7 +3,7 @@ use std::fmt::{self, Debug, Formatter};
 use ecow::{eco_format, EcoVec};
 
 use super::{Array, Dict, FromValue, IntoValue, Str, Value};
-use crate::diag::{bail, At, SourceResult};
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::syntax::{Span, Spanned};
 use crate::util::pretty_array_like;
 
@@ -195,6 +195,18 @@ impl Args {
             .filter_map(|item| item.name.clone().map(|name| (name, item.value.v.clone())))
             .collect()
     }
+
+    /// Get the positional argument at an index or the named argument with a
+    /// name.
+    ///
+    /// Fails if there is no such argument and no default is given.
+    pub fn at(&self, key: &Value, default: Option<Value>) -> StrResult<Value> {
+        match key {
+            Value::Int(index) => self.to_pos().at(*index, default),
+            Value::Str(name) => self.to_named().at(name, default),
+            v => bail!("expected integer or string, found {}", v.type_name()),
+        }
+    }
 }
 
 impl Debug for Args {
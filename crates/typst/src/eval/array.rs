@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Add, AddAssign};
 
@@ -95,9 +96,10 @@ impl Array {
         self.0.push(value);
     }
 
-    /// Remove the last value in the array.
-    pub fn pop(&mut self) -> StrResult<Value> {
-        self.0.pop().ok_or_else(array_is_empty)
+    /// Remove and return the last value in the array. If the array is empty,
+    /// return the `default` value or fail if there is none.
+    pub fn pop(&mut self, default: Option<Value>) -> StrResult<Value> {
+        self.0.pop().or(default).ok_or_else(array_is_empty)
     }
 
     /// Insert a value at the specified index.
@@ -121,8 +123,10 @@ impl Array {
     }
 
     /// Whether the array contains a specific value.
+    ///
+    /// Uses the same notion of equality as the `==` operator.
     pub fn contains(&self, value: &Value) -> bool {
-        self.0.contains(value)
+        self.0.iter().any(|v| ops::equal(v, value))
     }
 
     /// Return the first matching item.
@@ -297,44 +301,59 @@ impl Array {
             .collect()
     }
 
-    /// Return a sorted version of this array, optionally by a given key function.
+    /// Return a sorted version of this array, optionally by given key
+    /// functions.
+    ///
+    /// Ties on one key are broken by the next one. The sort is stable, also
+    /// when sorting in descending order. Keys that are `none` always come last.
     ///
-    /// Returns an error if two values could not be compared or if the key function (if given)
-    /// yields an error.
+    /// Returns an error if two values could not be compared or if a key
+    /// function yields an error.
     pub fn sorted(
         &self,
         vm: &mut Vm,
         span: Span,
-        key: Option<Func>,
+        key: Option<SortKey>,
+        descending: bool,
     ) -> SourceResult<Self> {
+        let keys = self
+            .iter()
+            .map(|item| match &key {
+                Some(SortKey(funcs)) => funcs
+                    .iter()
+                    .map(|f| f.call_vm(vm, Args::new(f.span(), [item.clone()])))
+                    .collect(),
+                None => Ok(vec![item.clone()]),
+            })
+            .collect::<SourceResult<Vec<Vec<Value>>>>()?;
+
         let mut result = Ok(());
-        let mut vec = self.0.clone();
-        let mut key_of = |x: Value| match &key {
-            // NOTE: We are relying on `comemo`'s memoization of function
-            // evaluation to not excessively reevaluate the `key`.
-            Some(f) => f.call_vm(vm, Args::new(f.span(), [x])),
-            None => Ok(x),
-        };
-        vec.make_mut().sort_by(|a, b| {
-            // Until we get `try` blocks :)
-            match (key_of(a.clone()), key_of(b.clone())) {
-                (Ok(a), Ok(b)) => {
-                    typst::eval::ops::compare(&a, &b).unwrap_or_else(|err| {
-                        if result.is_ok() {
-                            result = Err(err).at(span);
+        let mut indices: Vec<usize> = (0..keys.len()).collect();
+        indices.sort_by(|&i, &j| {
+            for (a, b) in keys[i].iter().zip(&keys[j]) {
+                let ordering = match (a, b) {
+                    (Value::None, Value::None) => Ordering::Equal,
+                    (Value::None, _) => Ordering::Greater,
+                    (_, Value::None) => Ordering::Less,
+                    _ => match typst::eval::ops::compare(a, b) {
+                        Ok(ordering) if descending => ordering.reverse(),
+                        Ok(ordering) => ordering,
+                        Err(err) => {
+                            if result.is_ok() {
+                                result = Err(err).at(span);
+                            }
+                            Ordering::Equal
                         }
-                        Ordering::Equal
-                    })
-                }
-                (Err(e), _) | (_, Err(e)) => {
-                    if result.is_ok() {
-                        result = Err(e);
-                    }
-                    Ordering::Equal
+                    },
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
                 }
             }
+            Ordering::Equal
         });
-        result.map(|_| vec.into())
+
+        result.map(|_| indices.into_iter().map(|i| self.0[i].clone()).collect())
     }
 
     /// Repeat this array `n` times.
@@ -363,33 +382,41 @@ impl Array {
             .collect()
     }
 
-    /// Deduplicates all items in the array.
+    /// Deduplicates all items in the array, keeping the first occurrence of
+    /// each item.
     pub fn dedup(&self, vm: &mut Vm, key: Option<Func>) -> SourceResult<Self> {
-        let mut out = EcoVec::with_capacity(self.0.len());
-        let mut key_of = |x: Value| match &key {
-            // NOTE: We are relying on `comemo`'s memoization of function
-            // evaluation to not excessively reevaluate the `key`.
-            Some(f) => f.call_vm(vm, Args::new(f.span(), [x])),
-            None => Ok(x),
+        let keys = match &key {
+            Some(f) => self
+                .iter()
+                .map(|x| f.call_vm(vm, Args::new(f.span(), [x.clone()])))
+                .collect::<SourceResult<Vec<_>>>()?,
+            None => self.0.to_vec(),
         };
 
-        // This algorithm is O(N^2) because we cannot rely on `HashSet` since:
-        // 1. We would like to preserve the order of the elements.
-        // 2. We cannot hash arbitrary `Value`.
-        'outer: for value in self.iter() {
-            let key = key_of(value.clone())?;
-            if out.is_empty() {
-                out.push(value.clone());
-                continue;
-            }
-
-            for second in out.iter() {
-                if typst::eval::ops::equal(&key, &key_of(second.clone())?) {
-                    continue 'outer;
+        // If all keys are simple enough that hashing agrees with equality, we
+        // can track the seen ones in a set. Otherwise, we have to compare each
+        // key with all kept ones, which is O(N^2). Floats, for instance, compare
+        // equal to integers and thus take the slow path.
+        let mut out = EcoVec::with_capacity(self.0.len());
+        if let Some(hashed) = keys.iter().map(DedupKey::new).collect::<Option<Vec<_>>>() {
+            let mut seen = HashSet::with_capacity(hashed.len());
+            for (value, key) in self.iter().zip(hashed) {
+                if seen.insert(key) {
+                    out.push(value.clone());
                 }
             }
+        } else {
+            let mut kept: Vec<&Value> = Vec::with_capacity(keys.len());
+            'outer: for (value, key) in self.iter().zip(&keys) {
+                for second in &kept {
+                    if ops::equal(key, second) {
+                        continue 'outer;
+                    }
+                }
 
-            out.push(value.clone());
+                kept.push(key);
+                out.push(value.clone());
+            }
         }
 
         Ok(Self(out))
@@ -521,6 +548,41 @@ fn array_is_empty() -> EcoString {
     "array is empty".into()
 }
 
+/// A deduplication key for which hashing agrees with value equality.
+#[derive(PartialEq, Eq, Hash)]
+enum DedupKey<'a> {
+    None,
+    Auto,
+    Bool(bool),
+    Int(i64),
+    Str(&'a str),
+    Label(&'a str),
+}
+
+impl<'a> DedupKey<'a> {
+    /// Create a key from a value, if possible.
+    fn new(value: &'a Value) -> Option<Self> {
+        Some(match value {
+            Value::None => Self::None,
+            Value::Auto => Self::Auto,
+            Value::Bool(v) => Self::Bool(*v),
+            Value::Int(v) => Self::Int(*v),
+            Value::Str(v) => Self::Str(v.as_str()),
+            Value::Label(v) => Self::Label(v.0.as_str()),
+            _ => return None,
+        })
+    }
+}
+
+/// One or multiple key functions to sort an array by.
+pub struct SortKey(Vec<Func>);
+
+cast! {
+    SortKey,
+    v: Func => Self(vec![v]),
+    v: Array => Self(v.into_iter().map(Value::cast).collect::<StrResult<_>>()?),
+}
+
 /// The out of bounds access error message.
 #[cold]
 fn out_of_bounds(index: i64, len: usize) -> EcoString {
@@ -6,9 +6,9 @@ use std::sync::Arc;
 use ecow::{eco_format, EcoString};
 use serde::{Serialize, Serializer};
 
-use super::{array, Array, Str, Value};
-use crate::diag::StrResult;
-use crate::syntax::is_ident;
+use super::{array, cast, Args, Array, Func, Str, Value, Vm};
+use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::syntax::{is_ident, Span};
 use crate::util::{pretty_array_like, separated_list, ArcExt};
 
 /// Create a new [`Dict`] from key-value pairs.
@@ -82,11 +82,12 @@ impl Dict {
         Arc::make_mut(&mut self.0).insert(key, value);
     }
 
-    /// Remove a mapping by `key` and return the value.
-    pub fn remove(&mut self, key: &str) -> StrResult<Value> {
+    /// Remove a mapping by `key` and return the value. If the key is missing,
+    /// return the `default` value or fail if there is none.
+    pub fn remove(&mut self, key: &str, default: Option<Value>) -> StrResult<Value> {
         match Arc::make_mut(&mut self.0).shift_remove(key) {
             Some(value) => Ok(value),
-            None => Err(missing_key(key)),
+            None => default.ok_or_else(|| missing_key(key)),
         }
     }
 
@@ -118,6 +119,31 @@ impl Dict {
             .collect()
     }
 
+    /// Transform each value in the dictionary with a function.
+    pub fn map_values(&self, vm: &mut Vm, func: Func) -> SourceResult<Self> {
+        self.iter()
+            .map(|(key, value)| {
+                let args = Args::new(func.span(), [value.clone()]);
+                Ok((key.clone(), func.call_vm(vm, args)?))
+            })
+            .collect()
+    }
+
+    /// Transform each key in the dictionary with a function. Fails if two keys
+    /// are mapped to the same new key.
+    pub fn map_keys(&self, vm: &mut Vm, func: Func, span: Span) -> SourceResult<Self> {
+        let mut map = IndexMap::with_capacity(self.len());
+        for (key, value) in self.iter() {
+            let args = Args::new(func.span(), [key.clone()]);
+            let new = func.call_vm(vm, args)?.cast::<DictKey>().at(func.span())?.0;
+            if map.contains_key(&new) {
+                bail!(span, "multiple keys are mapped to {new:?}");
+            }
+            map.insert(new, value.clone());
+        }
+        Ok(map.into())
+    }
+
     /// Iterate over pairs of references to the contained keys and values.
     pub fn iter(&self) -> indexmap::map::Iter<Str, Value> {
         self.0.iter()
@@ -234,6 +260,26 @@ impl From<IndexMap<Str, Value>> for Dict {
     }
 }
 
+/// A key for a dictionary entry.
+///
+/// Dictionaries are keyed by strings. Integers are accepted as keys, too, and
+/// stand for their decimal representation, so `{1}` and `{"1"}` refer to the
+/// same entry.
+pub struct DictKey(pub Str);
+
+impl From<i64> for DictKey {
+    fn from(int: i64) -> Self {
+        Self(eco_format!("{int}").into())
+    }
+}
+
+cast! {
+    DictKey,
+    self => self.0.into_value(),
+    v: Str => Self(v),
+    v: i64 => Self::from(v),
+}
+
 /// The missing key access error message.
 #[cold]
 fn missing_key(key: &str) -> EcoString {
@@ -25,6 +25,12 @@ pub(crate) fn field(value: &Value, field: &str) -> StrResult<Value> {
             "length" => rel.abs.into_value(),
             _ => return missing(),
         },
+        Value::Array(array) => match field {
+            "len" => array.len().into_value(),
+            "first" => array.first()?.clone(),
+            "last" => array.last()?.clone(),
+            _ => return missing(),
+        },
         Value::Dyn(dynamic) => {
             if let Some(stroke) = dynamic.downcast::<PartialStroke>() {
                 match field {
@@ -86,6 +92,7 @@ pub fn fields_on(type_name: &str) -> &[&'static str] {
     match type_name {
         "length" => &["em", "abs"],
         "relative length" => &["ratio", "length"],
+        "array" => &["len", "first", "last"],
         "stroke" => &["paint", "thickness", "cap", "join", "dash", "miter-limit"],
         "2d alignment" => &["x", "y"],
         _ => &[],
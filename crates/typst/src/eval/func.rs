@@ -1,19 +1,21 @@
+use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use comemo::{Prehashed, Tracked, TrackedMut};
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use once_cell::sync::Lazy;
 
 use super::{
-    cast, Args, CastInfo, Eval, FlowEvent, IntoValue, Route, Scope, Scopes, Tracer,
-    Value, Vm,
+    cast, dict, ops, Args, Array, CastInfo, Dict, Eval, FlowEvent, IntoValue, Limits,
+    Rng, Route, Scope, Scopes, Tracer, Value, Vm,
 };
-use crate::diag::{bail, SourceResult, StrResult};
+use crate::diag::{bail, At, Hint, SourceResult, StrResult};
 use crate::model::{DelayedErrors, ElemFunc, Introspector, Locator, Vt};
 use crate::syntax::ast::{self, AstNode, Expr, Ident};
-use crate::syntax::{FileId, Span, SyntaxNode};
+use crate::syntax::{FileId, Span, Spanned, SyntaxNode};
+use crate::util::hash128;
 use crate::World;
 
 /// An evaluatable function.
@@ -37,6 +39,10 @@ enum Repr {
     Closure(Arc<Prehashed<Closure>>),
     /// A nested function with pre-applied arguments.
     With(Arc<(Func, Args)>),
+    /// A nested function that is deprecated, with a message for its callers.
+    Deprecated(Arc<(Func, EcoString)>),
+    /// A nested function whose results are cached.
+    Memoized(Arc<Memoized>),
 }
 
 impl Func {
@@ -47,6 +53,8 @@ impl Func {
             Repr::Elem(func) => Some(func.info().name),
             Repr::Closure(closure) => closure.name.as_deref(),
             Repr::With(arc) => arc.0.name(),
+            Repr::Deprecated(arc) => arc.0.name(),
+            Repr::Memoized(memoized) => memoized.func.name(),
         }
     }
 
@@ -57,6 +65,26 @@ impl Func {
             Repr::Elem(func) => Some(func.info()),
             Repr::Closure(_) => None,
             Repr::With(arc) => arc.0.info(),
+            Repr::Deprecated(arc) => arc.0.info(),
+            Repr::Memoized(memoized) => memoized.func.info(),
+        }
+    }
+
+    /// Describe the function's parameters as an array of dictionaries with the
+    /// keys `name`, `named`, `has-default` and `sink`.
+    ///
+    /// Arguments that were pre-applied with [`with`](Self::with) are not taken
+    /// into account.
+    pub fn params(&self) -> Array {
+        match &self.repr {
+            Repr::Native(native) => {
+                native.info.params.iter().map(describe_info).collect()
+            }
+            Repr::Elem(func) => func.info().params.iter().map(describe_info).collect(),
+            Repr::Closure(closure) => closure.params.iter().map(describe_param).collect(),
+            Repr::With(arc) => arc.0.params(),
+            Repr::Deprecated(arc) => arc.0.params(),
+            Repr::Memoized(memoized) => memoized.func.params(),
         }
     }
 
@@ -92,31 +120,80 @@ impl Func {
                 args.finish()?;
                 Ok(Value::Content(value))
             }
-            Repr::Closure(closure) => {
-                // Determine the route inside the closure.
-                let fresh = Route::new(closure.location);
-                let route =
-                    if vm.location.is_detached() { fresh.track() } else { vm.route };
-
-                Closure::call(
-                    self,
-                    vm.world(),
-                    route,
-                    vm.vt.introspector,
-                    vm.vt.locator.track(),
-                    TrackedMut::reborrow_mut(&mut vm.vt.delayed),
-                    TrackedMut::reborrow_mut(&mut vm.vt.tracer),
-                    vm.depth + 1,
-                    args,
-                )
-            }
+            Repr::Closure(closure) => self.call_closure(closure, vm, args),
             Repr::With(arc) => {
                 args.items = arc.1.items.iter().cloned().chain(args.items).collect();
                 arc.0.call_vm(vm, args)
             }
+            Repr::Deprecated(arc) => arc.0.call_vm(vm, args),
+            Repr::Memoized(memoized) => {
+                let key = hash128(
+                    &args
+                        .items
+                        .iter()
+                        .map(|arg| (&arg.name, &arg.value.v))
+                        .collect::<Vec<_>>(),
+                );
+
+                if let Some(value) = memoized.cache.lock().unwrap().get(&key) {
+                    return Ok(value.clone());
+                }
+
+                // A memoized closure binds its name to the memoized function
+                // so that its recursive calls are cached, too.
+                let value = match &memoized.func.repr {
+                    Repr::Closure(closure) => self.call_closure(closure, vm, args)?,
+                    _ => memoized.func.call_vm(vm, args)?,
+                };
+
+                let mut cache = memoized.cache.lock().unwrap();
+                if cache.len() < Memoized::CAPACITY {
+                    cache.insert(key, value.clone());
+                }
+                Ok(value)
+            }
         }
     }
 
+    /// Call a closure, binding its name to this function.
+    fn call_closure(
+        &self,
+        closure: &Closure,
+        vm: &mut Vm,
+        args: Args,
+    ) -> SourceResult<Value> {
+        // Count the call, so that runaway recursions and callbacks are
+        // bounded, too.
+        let span = args.span;
+        vm.count_call(span)?;
+
+        // Determine the route inside the closure.
+        let fresh = Route::new(closure.location);
+        let route = if vm.location.is_detached() { fresh.track() } else { vm.route };
+
+        // The call gets the full limits instead of what is left of the
+        // caller's budgets, so that equal calls share a cache entry.
+        let outcome = Closure::call(
+            self,
+            vm.world(),
+            route,
+            vm.vt.introspector,
+            vm.vt.locator.track(),
+            TrackedMut::reborrow_mut(&mut vm.vt.delayed),
+            TrackedMut::reborrow_mut(&mut vm.vt.tracer),
+            vm.depth + 1,
+            vm.limits,
+            vm.rng.clone(),
+            args,
+        );
+
+        // Account for what the call used, even if it failed, before checking
+        // the caller's budgets.
+        vm.rng = outcome.rng;
+        vm.spend(outcome.iterations, outcome.calls, span)?;
+        outcome.value
+    }
+
     /// Call the function with a Vt.
     #[tracing::instrument(skip_all)]
     pub fn call_vt<T: IntoValue>(
@@ -145,10 +222,56 @@ impl Func {
         Self { repr: Repr::With(Arc::new((self, args))), span }
     }
 
+    /// Mark the function as deprecated.
+    ///
+    /// Calls to it will then produce a warning with the given message.
+    pub fn deprecated(self, message: EcoString) -> Self {
+        let span = self.span;
+        Self {
+            repr: Repr::Deprecated(Arc::new((self, message))),
+            span,
+        }
+    }
+
+    /// The deprecation message of the function, if it is deprecated.
+    pub fn deprecation(&self) -> Option<&str> {
+        match &self.repr {
+            Repr::Deprecated(arc) => Some(&arc.1),
+            Repr::With(arc) => arc.0.deprecation(),
+            _ => None,
+        }
+    }
+
+    /// Cache the results of the function by its arguments.
+    ///
+    /// This only makes sense for functions without side effects.
+    pub fn memoized(self) -> Self {
+        let span = self.span;
+        let memoized = Memoized { func: self, cache: Mutex::default() };
+        Self { repr: Repr::Memoized(Arc::new(memoized)), span }
+    }
+
+    /// The closure that is called when calling this function directly.
+    fn closure(&self) -> Option<&Closure> {
+        match &self.repr {
+            Repr::Closure(closure) => Some(closure),
+            Repr::Memoized(memoized) => memoized.func.closure(),
+            _ => None,
+        }
+    }
+
+    /// Whether the call at the given span is a self-call in tail position of
+    /// this closure's body.
+    pub(super) fn is_tail_call(&self, span: Span) -> bool {
+        self.closure()
+            .is_some_and(|closure| closure.tail_calls.contains(&span))
+    }
+
     /// Extract the element function, if it is one.
     pub fn element(&self) -> Option<ElemFunc> {
-        match self.repr {
-            Repr::Elem(func) => Some(func),
+        match &self.repr {
+            Repr::Elem(func) => Some(*func),
+            Repr::Deprecated(arc) => arc.0.element(),
             _ => None,
         }
     }
@@ -174,6 +297,8 @@ impl Func {
                 Err(eco_format!("cannot access fields on user-defined functions"))
             }
             Repr::With(arc) => arc.0.get(field),
+            Repr::Deprecated(arc) => arc.0.get(field),
+            Repr::Memoized(memoized) => memoized.func.get(field),
         }
     }
 }
@@ -292,11 +417,82 @@ pub struct ParamInfo {
     pub settable: bool,
 }
 
+/// Describe a native parameter for [`Func::params`].
+fn describe_info(info: &ParamInfo) -> Value {
+    Value::Dict(param_dict(
+        info.name.into_value(),
+        info.named && !info.positional,
+        info.default.is_some(),
+        info.variadic,
+    ))
+}
+
+/// Describe a closure parameter for [`Func::params`].
+fn describe_param(param: &Param) -> Value {
+    Value::Dict(match param {
+        Param::Pos(ast::Pattern::Normal(ast::Expr::Ident(ident))) => {
+            param_dict(ident.as_str().into_value(), false, false, false)
+        }
+        Param::Pos(_) => param_dict(Value::None, false, false, false),
+        Param::Typed(ident, _) => {
+            param_dict(ident.as_str().into_value(), false, false, false)
+        }
+        Param::Named(ident, _) => {
+            param_dict(ident.as_str().into_value(), true, true, false)
+        }
+        Param::Sink(ident) | Param::NamedSink(ident) => param_dict(
+            ident.as_ref().map(|ident| ident.as_str()).into_value(),
+            matches!(param, Param::NamedSink(_)),
+            false,
+            true,
+        ),
+    })
+}
+
+/// Create the dictionary describing a single parameter.
+fn param_dict(name: Value, named: bool, has_default: bool, sink: bool) -> Dict {
+    dict! {
+        "name" => name,
+        "named" => named,
+        "has-default" => has_default,
+        "sink" => sink,
+    }
+}
+
+/// A function whose results are cached by its arguments.
+struct Memoized {
+    /// The wrapped function.
+    func: Func,
+    /// The cached results, keyed by the hash of the argument names and values.
+    cache: Mutex<HashMap<u128, Value>>,
+}
+
+impl Memoized {
+    /// The maximum number of cached results. Results for further arguments
+    /// are computed on every call.
+    const CAPACITY: usize = 10_000;
+}
+
+impl Hash for Memoized {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.func.hash(state);
+    }
+}
+
+impl PartialEq for Memoized {
+    fn eq(&self, other: &Self) -> bool {
+        self.func == other.func
+    }
+}
+
 /// A user-defined closure.
 #[derive(Hash)]
 pub(super) struct Closure {
     /// The source file where the closure was defined.
     pub location: FileId,
+    /// The limits of the evaluation that created the closure, which also
+    /// bound its calls.
+    pub limits: Limits,
     /// The name of the closure.
     pub name: Option<Ident>,
     /// Captured values from outer scopes.
@@ -305,6 +501,8 @@ pub(super) struct Closure {
     pub params: Vec<Param>,
     /// The expression the closure should evaluate to.
     pub body: Expr,
+    /// The spans of the self-calls in tail position of the body.
+    pub tail_calls: Vec<Span>,
 }
 
 /// A closure parameter.
@@ -312,14 +510,42 @@ pub(super) struct Closure {
 pub enum Param {
     /// A positional parameter: `x`.
     Pos(ast::Pattern),
+    /// A positional parameter with a type annotation: `n as int`.
+    Typed(Ident, Ident),
     /// A named parameter with a default value: `draw: false`.
     Named(Ident, Value),
     /// An argument sink: `..args`.
     Sink(Option<Ident>),
+    /// A sink for remaining named arguments: the `..kwargs` in
+    /// `(..args, ..kwargs)`.
+    ///
+    /// If present, the preceding sink only collects positional arguments.
+    NamedSink(Option<Ident>),
+}
+
+/// The result of a closure call, along with what its caller must account for.
+#[derive(Clone)]
+struct Outcome {
+    /// The value returned by the closure or the errors of the call.
+    value: SourceResult<Value>,
+    /// The number of loop iterations performed by the call.
+    iterations: usize,
+    /// The number of closure calls performed by the call.
+    calls: usize,
+    /// The state of the random number generator after the call.
+    rng: Rng,
 }
 
 impl Closure {
     /// Call the function in the context with the arguments.
+    ///
+    /// This is memoized: Since a closure hashes its body, parameters and
+    /// captured values, and arguments hash by value, repeated calls with equal
+    /// arguments are served from the cache, also across reparses. Calls that
+    /// draw random numbers advance the generator and thus aren't repeated.
+    ///
+    /// The call is bounded by the full `limits`. The resources it used are
+    /// part of the outcome, so that the caller can check its own budgets.
     #[comemo::memoize]
     #[tracing::instrument(skip_all)]
     #[allow(clippy::too_many_arguments)]
@@ -332,17 +558,11 @@ impl Closure {
         delayed: TrackedMut<DelayedErrors>,
         tracer: TrackedMut<Tracer>,
         depth: usize,
-        mut args: Args,
-    ) -> SourceResult<Value> {
-        let closure = match &this.repr {
-            Repr::Closure(closure) => closure,
-            _ => panic!("`this` must be a closure"),
-        };
-
-        // Don't leak the scopes from the call site. Instead, we use the scope
-        // of captured variables we collected earlier.
-        let mut scopes = Scopes::new(None);
-        scopes.top = closure.captured.clone();
+        limits: Limits,
+        rng: Rng,
+        args: Args,
+    ) -> Outcome {
+        let closure = this.closure().expect("`this` must be a closure");
 
         // Prepare VT.
         let mut locator = Locator::chained(locator);
@@ -355,43 +575,118 @@ impl Closure {
         };
 
         // Prepare VM.
-        let mut vm = Vm::new(vt, route, closure.location, scopes);
+        let mut vm = Vm::new(vt, route, closure.location, Scopes::new(None));
         vm.depth = depth;
+        vm.limits = limits.min(closure.limits);
+        vm.rng = rng;
+        vm.this = Some(this.clone());
+
+        let value = closure.run(&mut vm, this, args);
+        Outcome {
+            value,
+            iterations: vm.iterations,
+            calls: vm.calls,
+            rng: vm.rng,
+        }
+    }
+
+    /// Evaluate the body with the arguments.
+    fn run(&self, vm: &mut Vm, this: &Func, mut args: Args) -> SourceResult<Value> {
+        // Self-calls in tail position are evaluated by looping instead of
+        // recursing. The output produced in front of such a call is joined
+        // with the call's value once the loop has finished.
+        let mut outputs = vec![];
+        loop {
+            // Closures are pure apart from random numbers, so a self-call
+            // with the same arguments and generator state would repeat
+            // forever.
+            let state = hash128(&(&args, &vm.rng));
+            self.bind(vm, this, args)?;
+
+            // Handle control flow.
+            let result = self.body.eval(vm);
+            let output = match vm.flow.take() {
+                Some(FlowEvent::Return(_, Some(explicit))) => explicit,
+                Some(FlowEvent::Return(_, None)) => result?,
+                Some(FlowEvent::TailCall(span, next, explicit)) => {
+                    if hash128(&(&next, &vm.rng)) == state {
+                        bail!(span, "function calls itself with the same arguments");
+                    }
+                    outputs.push((explicit.map_or(result, Ok)?, span));
+                    vm.count_call(span)?;
+                    args = next;
+                    continue;
+                }
+                Some(flow) => bail!(flow.forbidden()),
+                None => result?,
+            };
+
+            let value =
+                outputs.into_iter().rev().try_fold(output, |acc, (output, span)| {
+                    ops::join(output, acc).at(span)
+                })?;
+
+            return Ok(value);
+        }
+    }
+
+    /// Bind the arguments to the parameters in a fresh scope.
+    fn bind(&self, vm: &mut Vm, this: &Func, mut args: Args) -> SourceResult<()> {
+        // Don't leak the scopes from the call site. Instead, we use the scope
+        // of captured variables we collected earlier.
+        vm.scopes.top = self.captured.clone();
 
         // Provide the closure itself for recursive calls.
-        if let Some(name) = &closure.name {
+        if let Some(name) = &self.name {
             vm.define(name.clone(), Value::Func(this.clone()));
         }
 
         // Parse the arguments according to the parameter list.
-        let num_pos_params =
-            closure.params.iter().filter(|p| matches!(p, Param::Pos(_))).count();
+        let num_pos_params = self
+            .params
+            .iter()
+            .filter(|p| matches!(p, Param::Pos(_) | Param::Typed(..)))
+            .count();
         let num_pos_args = args.to_pos().len();
         let sink_size = num_pos_args.checked_sub(num_pos_params);
 
         let mut sink = None;
+        let mut named_sink = None;
         let mut sink_pos_values = None;
-        for p in &closure.params {
+        for p in &self.params {
             match p {
                 Param::Pos(pattern) => match pattern {
                     ast::Pattern::Normal(ast::Expr::Ident(ident)) => {
-                        vm.define(ident.clone(), args.expect::<Value>(ident)?)
+                        vm.define(ident.clone(), args.expect::<Value>(ident)?);
                     }
                     ast::Pattern::Normal(_) => unreachable!(),
                     _ => {
                         super::define_pattern(
-                            &mut vm,
+                            vm,
                             pattern,
                             args.expect::<Value>("pattern parameter")?,
                         )?;
                     }
                 },
+                Param::Typed(ident, ty) => {
+                    let Spanned { v, span } = args.expect::<Spanned<Value>>(ident)?;
+                    let value = super::cast_to_type(ty, v)
+                        .expect("annotation should name a known type")
+                        .hint(eco_format!(
+                            "parameter `{}` is annotated with type `{}`",
+                            ident.as_str(),
+                            ty.as_str()
+                        ))
+                        .at(span)?;
+                    vm.define(ident.clone(), value);
+                }
                 Param::Sink(ident) => {
                     sink = ident.clone();
                     if let Some(sink_size) = sink_size {
                         sink_pos_values = Some(args.consume(sink_size)?);
                     }
                 }
+                Param::NamedSink(ident) => named_sink = Some(ident.clone()),
                 Param::Named(ident, default) => {
                     let value =
                         args.named::<Value>(ident)?.unwrap_or_else(|| default.clone());
@@ -400,7 +695,18 @@ impl Closure {
             }
         }
 
-        if let Some(sink) = sink {
+        if let Some(named_sink) = named_sink {
+            // Positional and named arguments are collected separately.
+            let remaining_args = args.take();
+            if let Some(sink) = sink {
+                let values = sink_pos_values.unwrap_or_default();
+                let pos: Array = values.into_iter().map(|arg| arg.value.v).collect();
+                vm.define(sink, pos);
+            }
+            if let Some(named_sink) = named_sink {
+                vm.define(named_sink, remaining_args.to_named());
+            }
+        } else if let Some(sink) = sink {
             let mut remaining_args = args.take();
             if let Some(sink_pos_values) = sink_pos_values {
                 remaining_args.items.extend(sink_pos_values);
@@ -409,18 +715,7 @@ impl Closure {
         }
 
         // Ensure all arguments have been used.
-        args.finish()?;
-
-        // Handle control flow.
-        let result = closure.body.eval(&mut vm);
-        match vm.flow {
-            Some(FlowEvent::Return(_, Some(explicit))) => return Ok(explicit),
-            Some(FlowEvent::Return(_, None)) => {}
-            Some(flow) => bail!(flow.forbidden()),
-            None => {}
-        }
-
-        result
+        args.finish()
     }
 }
 
@@ -435,6 +730,54 @@ cast! {
     self => Value::Func(self.into()),
 }
 
+/// Collect the spans of the calls to a closure named `name` that are in tail
+/// position of its body.
+///
+/// The value of such a call directly becomes the value of the closure's call,
+/// except for output produced in front of it that is joined with it.
+pub(super) fn tail_calls(name: &str, body: &Expr) -> Vec<Span> {
+    fn visit(name: &str, expr: &Expr, tail: bool, calls: &mut Vec<Span>) {
+        match expr {
+            Expr::FuncCall(call) => {
+                if tail
+                    && !call.span().is_detached()
+                    && matches!(call.callee(), Expr::Ident(ident) if ident.as_str() == name)
+                {
+                    calls.push(call.span());
+                }
+            }
+            Expr::Parenthesized(parens) => visit(name, &parens.expr(), tail, calls),
+            Expr::Code(block) => {
+                // Set and show rules style the remainder of the block, so
+                // nothing after them is in tail position.
+                let exprs: Vec<_> = block.body().exprs().collect();
+                let styled =
+                    exprs.iter().any(|e| matches!(e, Expr::Set(_) | Expr::Show(_)));
+                for (i, expr) in exprs.iter().enumerate() {
+                    visit(name, expr, tail && !styled && i + 1 == exprs.len(), calls);
+                }
+            }
+            Expr::Conditional(cond) => {
+                visit(name, &cond.if_body(), tail, calls);
+                if let Some(else_body) = cond.else_body() {
+                    visit(name, &else_body, tail, calls);
+                }
+            }
+            // An explicit return discards all other output.
+            Expr::Return(ret) => {
+                if let Some(body) = ret.body() {
+                    visit(name, &body, true, calls);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut calls = vec![];
+    visit(name, body, true, &mut calls);
+    calls
+}
+
 /// A visitor that determines which variables to capture for a closure.
 pub(super) struct CapturesVisitor<'a> {
     external: &'a Scopes<'a>,
@@ -500,6 +843,7 @@ impl<'a> CapturesVisitor<'a> {
                             }
                         }
                         ast::Param::Named(named) => self.bind(named.name()),
+                        ast::Param::Typed(typed) => self.bind(typed.name()),
                         ast::Param::Sink(spread) => {
                             self.bind(spread.name().unwrap_or_default())
                         }
@@ -536,6 +880,10 @@ impl<'a> CapturesVisitor<'a> {
 
                 self.visit(expr.body().as_untyped());
                 self.internal.exit();
+
+                if let Some(else_body) = expr.else_body() {
+                    self.visit(else_body.as_untyped());
+                }
             }
 
             // An import contains items, but these are active only after the
@@ -544,7 +892,7 @@ impl<'a> CapturesVisitor<'a> {
                 self.visit(expr.source().as_untyped());
                 if let Some(ast::Imports::Items(items)) = expr.imports() {
                     for item in items {
-                        self.bind(item);
+                        self.bind(item.bound_name());
                     }
                 }
             }
@@ -631,6 +979,7 @@ mod tests {
         test("#for x in y { x + z }", &["y", "z"]);
         test("#for (x, y) in y { x + y }", &["y"]);
         test("#for x in y {} #x", &["x", "y"]);
+        test("#for x in y {} else { x + z }", &["x", "y", "z"]);
 
         // Import.
         test("#import z: x, y", &["z"]);
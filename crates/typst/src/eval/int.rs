@@ -1,6 +1,23 @@
 use std::num::{NonZeroI64, NonZeroIsize, NonZeroU64, NonZeroUsize};
 
 use super::{cast, Value};
+use crate::diag::{bail, StrResult};
+
+/// Convert a float to an integer, truncating towards zero.
+///
+/// Fails for NaN and for floats outside of the range of 64-bit integers.
+pub fn float_to_int(float: f64) -> StrResult<i64> {
+    if float.is_nan() {
+        bail!("cannot convert NaN to an integer");
+    }
+
+    // The upper bound is exclusive as `i64::MAX as f64` rounds up to 2^63.
+    if float < i64::MIN as f64 || float >= i64::MAX as f64 {
+        bail!("number too large");
+    }
+
+    Ok(float as i64)
+}
 
 macro_rules! signed_int {
     ($($ty:ty)*) => {
@@ -6,7 +6,7 @@ use comemo::Tracked;
 use ecow::EcoString;
 use std::sync::OnceLock;
 
-use super::{Args, Dynamic, Module, NativeFunc, Value, Vm};
+use super::{Args, Dynamic, Limits, Module, NativeFunc, Value, Vm};
 use crate::diag::SourceResult;
 use crate::doc::Document;
 use crate::geom::{Abs, Dir};
@@ -25,6 +25,10 @@ pub struct Library {
     pub styles: Styles,
     /// Defines which standard library items fulfill which syntactical roles.
     pub items: LangItems,
+    /// The resource limits for evaluating documents.
+    pub limits: Limits,
+    /// The seed from which the random numbers of documents are drawn.
+    pub seed: u64,
 }
 
 /// Definition of library items the language is aware of.
@@ -49,8 +53,9 @@ pub struct LangItems {
     pub text_str: fn(&Content) -> Option<EcoString>,
     /// A smart quote: `'` or `"`.
     pub smart_quote: fn(double: bool) -> Content,
-    /// A paragraph break.
-    pub parbreak: fn() -> Content,
+    /// A paragraph break. Ends a list if it consists of two or more blank
+    /// lines.
+    pub parbreak: fn(ends_list: bool) -> Content,
     /// Strong content: `*Strong*`.
     pub strong: fn(body: Content) -> Content,
     /// Emphasized content: `_Emphasized_`.
@@ -58,11 +63,16 @@ pub struct LangItems {
     /// Raw text with optional syntax highlighting: `` `...` ``.
     pub raw: fn(text: EcoString, tag: Option<EcoString>, block: bool) -> Content,
     /// The language names and tags supported by raw text.
-    pub raw_languages: fn() -> Vec<(&'static str, Vec<&'static str>)>,
+    pub raw_languages: fn() -> &'static [(&'static str, Vec<&'static str>)],
     /// A hyperlink: `https://typst.org`.
     pub link: fn(url: EcoString) -> Content,
     /// A reference: `@target`, `@target[..]`.
     pub reference: fn(target: Label, supplement: Option<Content>) -> Content,
+    /// A reference to a footnote: `[^note]`. The first reference to a note in
+    /// the document shows its body, later ones refer back to it.
+    pub footnote: fn(name: EcoString) -> Content,
+    /// The definition of a footnote: `[^note]: Details`.
+    pub footnote_def: fn(name: EcoString, body: Content) -> Content,
     /// The keys contained in the bibliography and short descriptions of them.
     #[allow(clippy::type_complexity)]
     pub bibliography_keys:
@@ -71,8 +81,8 @@ pub struct LangItems {
     pub heading: fn(level: NonZeroUsize, body: Content) -> Content,
     /// The heading function.
     pub heading_func: ElemFunc,
-    /// An item in a bullet list: `- ...`.
-    pub list_item: fn(body: Content) -> Content,
+    /// An item in a bullet list: `- ...` or `- [x] ...`.
+    pub list_item: fn(checked: Option<bool>, body: Content) -> Content,
     /// An item in an enumeration (numbered list): `+ ...` or `1. ...`.
     pub enum_item: fn(number: Option<usize>, body: Content) -> Content,
     /// An item in a term list: `/ Term: Details`.
@@ -144,6 +154,8 @@ impl Hash for LangItems {
         self.raw_languages.hash(state);
         self.link.hash(state);
         self.reference.hash(state);
+        self.footnote.hash(state);
+        self.footnote_def.hash(state);
         (self.bibliography_keys as usize).hash(state);
         self.heading.hash(state);
         self.heading_func.hash(state);
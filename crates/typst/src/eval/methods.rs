@@ -2,11 +2,11 @@
 
 use ecow::{eco_format, EcoString};
 
-use super::{Args, IntoValue, Str, Value, Vm};
+use super::{float_to_int, Args, DictKey, IntoValue, Str, Value, Vm};
 use crate::diag::{At, Hint, SourceResult};
 use crate::eval::{bail, Datetime};
 use crate::geom::{Align, Axes, Color, Dir, Em, GenAlign};
-use crate::model::{Location, Selector};
+use crate::model::{Location, Selector, Styles};
 use crate::syntax::Span;
 
 /// Call a method on a value.
@@ -51,8 +51,34 @@ pub fn call(
             _ => return missing(),
         },
 
+        Value::Int(int) => match method {
+            "abs" => int
+                .checked_abs()
+                .ok_or("the result is too large")
+                .at(span)?
+                .into_value(),
+            "floor" | "ceil" | "trunc" => int.into_value(),
+            "round" => {
+                let digits: i64 = args.named("digits")?.unwrap_or(0);
+                round_int(int, digits).into_value()
+            }
+            _ => return missing(),
+        },
+
+        Value::Float(float) => match method {
+            "abs" => float.abs().into_value(),
+            "floor" => float_to_int(float.floor()).at(span)?.into_value(),
+            "ceil" => float_to_int(float.ceil()).at(span)?.into_value(),
+            "trunc" => float_to_int(float).at(span)?.into_value(),
+            "round" => {
+                let digits: i64 = args.named("digits")?.unwrap_or(0);
+                round_float(float, digits).into_value()
+            }
+            _ => return missing(),
+        },
+
         Value::Str(string) => match method {
-            "len" => string.len().into_value(),
+            "len" => string.len_in(args.named("unit")?.unwrap_or_default()).into_value(),
             "first" => string.first().at(span)?.into_value(),
             "last" => string.last().at(span)?.into_value(),
             "at" => string
@@ -67,13 +93,23 @@ pub fn call(
                 }
                 string.slice(start, end).at(span)?.into_value()
             }
-            "clusters" => string.clusters().into_value(),
+            "chars" | "clusters" | "graphemes" => string.clusters().into_value(),
+            "words" => {
+                let whitespace = args.named("whitespace")?.unwrap_or(false);
+                string.words(whitespace).into_value()
+            }
+            "sentences" => {
+                let whitespace = args.named("whitespace")?.unwrap_or(false);
+                string.sentences(whitespace).into_value()
+            }
             "codepoints" => string.codepoints().into_value(),
             "contains" => string.contains(args.expect("pattern")?).into_value(),
             "starts-with" => string.starts_with(args.expect("pattern")?).into_value(),
             "ends-with" => string.ends_with(args.expect("pattern")?).into_value(),
             "find" => string.find(args.expect("pattern")?).into_value(),
             "position" => string.position(args.expect("pattern")?).into_value(),
+            "rfind" => string.rfind(args.expect("pattern")?).into_value(),
+            "rposition" => string.rposition(args.expect("pattern")?).into_value(),
             "match" => string.match_(args.expect("pattern")?).into_value(),
             "matches" => string.matches(args.expect("pattern")?).into_value(),
             "replace" => {
@@ -89,6 +125,28 @@ pub fn call(
                 string.trim(pattern, at, repeat).into_value()
             }
             "split" => string.split(args.eat()?).into_value(),
+            "rev" => string.rev().into_value(),
+            "repeat" => {
+                let count = args.expect("count")?;
+                let with = args.named::<Str>("with")?;
+                string.repeat(count, with.as_deref()).at(span)?.into_value()
+            }
+            "lower" => string.lower().into_value(),
+            "upper" => string.upper().into_value(),
+            "title" => string.title().into_value(),
+            "to-int" => {
+                let base = args.named("base")?.unwrap_or(10);
+                let trim = args.named("trim")?.unwrap_or(false);
+                string.to_int(base, trim).at(span)?.into_value()
+            }
+            "to-float" => {
+                let trim = args.named("trim")?.unwrap_or(false);
+                string.to_float(trim).at(span)?.into_value()
+            }
+            "format" => {
+                let args = args.take();
+                string.format(&args.to_pos(), &args.to_named()).at(span)?.into_value()
+            }
             _ => return missing(),
         },
 
@@ -118,6 +176,10 @@ pub fn call(
                 .ok_or("this method can only be called on content returned by query(..)")
                 .at(span)?
                 .into_value(),
+            "styled" => content
+                .clone()
+                .styled_with_map(args.expect::<Styles>("styles")?)
+                .into_value(),
             _ => return missing(),
         },
 
@@ -154,7 +216,11 @@ pub fn call(
                 let last = args.named("last")?;
                 array.join(sep, last).at(span)?
             }
-            "sorted" => array.sorted(vm, span, args.named("key")?)?.into_value(),
+            "sorted" => {
+                let key = args.named("key")?;
+                let descending = args.named("descending")?.unwrap_or(false);
+                array.sorted(vm, span, key, descending)?.into_value()
+            }
             "zip" => array.zip(args.expect("other")?).into_value(),
             "enumerate" => array
                 .enumerate(args.named("start")?.unwrap_or(0))
@@ -167,16 +233,22 @@ pub fn call(
         Value::Dict(dict) => match method {
             "len" => dict.len().into_value(),
             "at" => dict
-                .at(&args.expect::<Str>("key")?, args.named("default")?)
+                .at(&args.expect::<DictKey>("key")?.0, args.named("default")?)
                 .at(span)?,
+            "contains" => dict.contains(&args.expect::<DictKey>("key")?.0).into_value(),
             "keys" => dict.keys().into_value(),
             "values" => dict.values().into_value(),
             "pairs" => dict.pairs().into_value(),
+            "map-values" => dict.map_values(vm, args.expect("function")?)?.into_value(),
+            "map-keys" => dict.map_keys(vm, args.expect("function")?, span)?.into_value(),
             _ => return missing(),
         },
 
         Value::Func(func) => match method {
+            "name" => func.name().into_value(),
+            "params" => func.params().into_value(),
             "with" => func.with(args.take()).into_value(),
+            "deprecated" => func.deprecated(args.expect("message")?).into_value(),
             "where" => {
                 let fields = args.to_named();
                 args.items.retain(|arg| arg.name.is_none());
@@ -213,9 +285,17 @@ pub fn call(
             _ => return missing(),
         },
 
-        Value::Args(args) => match method {
-            "pos" => args.to_pos().into_value(),
-            "named" => args.to_named().into_value(),
+        Value::Args(arguments) => match method {
+            "pos" => arguments.to_pos().into_value(),
+            "named" => match args.eat::<Str>()? {
+                Some(name) => {
+                    arguments.to_named().at(&name, args.named("default")?).at(span)?
+                }
+                None => arguments.to_named().into_value(),
+            },
+            "at" => arguments
+                .at(&args.expect::<Value>("key")?, args.named("default")?)
+                .at(span)?,
             _ => return missing(),
         },
 
@@ -293,6 +373,9 @@ pub fn call(
 }
 
 /// Call a mutating method on a value.
+///
+/// Returns the method's result, e.g. the removed value for `pop` and `remove`,
+/// or `none` for methods that only mutate.
 pub fn call_mut(
     value: &mut Value,
     method: &str,
@@ -306,7 +389,7 @@ pub fn call_mut(
     match value {
         Value::Array(array) => match method {
             "push" => array.push(args.expect("value")?),
-            "pop" => output = array.pop().at(span)?,
+            "pop" => output = array.pop(args.named("default")?).at(span)?,
             "insert" => {
                 array.insert(args.expect("index")?, args.expect("value")?).at(span)?
             }
@@ -315,9 +398,12 @@ pub fn call_mut(
         },
 
         Value::Dict(dict) => match method {
-            "insert" => dict.insert(args.expect::<Str>("key")?, args.expect("value")?),
+            "insert" => {
+                dict.insert(args.expect::<DictKey>("key")?.0, args.expect("value")?)
+            }
             "remove" => {
-                output = dict.remove(&args.expect::<EcoString>("key")?).at(span)?
+                let key = args.expect::<DictKey>("key")?.0;
+                output = dict.remove(&key, args.named("default")?).at(span)?
             }
             _ => return missing(),
         },
@@ -347,7 +433,7 @@ pub fn call_access<'a>(
             _ => return missing(),
         },
         Value::Dict(dict) => match method {
-            "at" => dict.at_mut(&args.expect::<Str>("key")?).at(span)?,
+            "at" => dict.at_mut(&args.expect::<DictKey>("key")?.0).at(span)?,
             _ => return missing(),
         },
         _ => return missing(),
@@ -358,6 +444,8 @@ pub fn call_access<'a>(
 }
 
 /// Whether a specific method is mutating.
+///
+/// Calls to such methods are dispatched to [`call_mut`].
 pub fn is_mutating(method: &str) -> bool {
     matches!(method, "push" | "pop" | "insert" | "remove")
 }
@@ -367,6 +455,44 @@ pub fn is_accessor(method: &str) -> bool {
     matches!(method, "first" | "last" | "at")
 }
 
+/// Round an integer to a power of ten, rounding halfway cases away from zero.
+///
+/// Non-negative digit counts leave the integer unchanged.
+fn round_int(int: i64, digits: i64) -> i64 {
+    if digits >= 0 {
+        return int;
+    }
+
+    let Some(factor) = u32::try_from(digits.unsigned_abs())
+        .ok()
+        .and_then(|exp| 10_i64.checked_pow(exp))
+    else {
+        return 0;
+    };
+
+    let rest = int % factor;
+    let down = int - rest;
+    if rest.unsigned_abs() * 2 >= factor as u64 {
+        down.saturating_add(rest.signum() * factor)
+    } else {
+        down
+    }
+}
+
+/// Round a float to the given number of decimal places, rounding halfway
+/// cases away from zero.
+fn round_float(float: f64, digits: i64) -> f64 {
+    let factor = 10.0_f64.powi(digits.clamp(-400, 400) as i32);
+    let scaled = float * factor;
+    if factor == 0.0 {
+        0.0
+    } else if !scaled.is_finite() {
+        float
+    } else {
+        scaled.round() / factor
+    }
+}
+
 /// The missing method error message.
 #[cold]
 fn missing_method(type_name: &str, method: &str) -> String {
@@ -386,24 +512,52 @@ pub fn methods_on(type_name: &str) -> &[(&'static str, bool)] {
             ("cmyk", false),
             ("luma", false),
         ],
+        "integer" => &[
+            ("abs", false),
+            ("ceil", false),
+            ("floor", false),
+            ("round", true),
+            ("trunc", false),
+        ],
+        "float" => &[
+            ("abs", false),
+            ("ceil", false),
+            ("floor", false),
+            ("round", true),
+            ("trunc", false),
+        ],
         "string" => &[
             ("len", false),
             ("at", true),
+            ("chars", false),
             ("clusters", false),
             ("codepoints", false),
             ("contains", true),
             ("ends-with", true),
             ("find", true),
             ("first", false),
+            ("format", true),
+            ("graphemes", false),
             ("last", false),
+            ("lower", false),
             ("match", true),
             ("matches", true),
             ("position", true),
+            ("repeat", true),
             ("replace", true),
+            ("rev", false),
+            ("rfind", true),
+            ("rposition", true),
+            ("sentences", true),
             ("slice", true),
             ("split", true),
             ("starts-with", true),
+            ("title", false),
+            ("to-float", false),
+            ("to-int", false),
             ("trim", true),
+            ("upper", false),
+            ("words", true),
         ],
         "bytes" => &[("len", false), ("at", true), ("slice", true)],
         "content" => &[
@@ -412,6 +566,7 @@ pub fn methods_on(type_name: &str) -> &[(&'static str, bool)] {
             ("at", true),
             ("fields", false),
             ("location", false),
+            ("styled", true),
         ],
         "array" => &[
             ("all", true),
@@ -441,17 +596,26 @@ pub fn methods_on(type_name: &str) -> &[(&'static str, bool)] {
         ],
         "dictionary" => &[
             ("at", true),
+            ("contains", true),
             ("insert", true),
             ("keys", false),
             ("len", false),
+            ("map-keys", true),
+            ("map-values", true),
             ("pairs", false),
             ("remove", true),
             ("values", false),
         ],
-        "function" => &[("where", true), ("with", true)],
+        "function" => &[
+            ("deprecated", true),
+            ("name", false),
+            ("params", false),
+            ("where", true),
+            ("with", true),
+        ],
         "length" => &[("pt", false), ("cm", false), ("mm", false), ("inches", false)],
         "angle" => &[("deg", false), ("rad", false)],
-        "arguments" => &[("named", false), ("pos", false)],
+        "arguments" => &[("at", true), ("named", false), ("pos", false)],
         "location" => &[("page", false), ("position", false), ("page-numbering", false)],
         "selector" => &[("or", true), ("and", true), ("before", true), ("after", true)],
         "direction" => {
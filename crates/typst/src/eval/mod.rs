@@ -23,6 +23,7 @@ mod methods;
 mod module;
 mod none;
 pub mod ops;
+mod rng;
 mod scope;
 mod symbol;
 mod tracer;
@@ -46,22 +47,25 @@ pub use self::cast::{
     cast, Cast, CastInfo, FromValue, IntoResult, IntoValue, Never, Reflect, Variadics,
 };
 pub use self::datetime::Datetime;
-pub use self::dict::{dict, Dict};
+pub use self::dict::{dict, Dict, DictKey};
 pub use self::fields::fields_on;
 pub use self::func::{Func, FuncInfo, NativeFunc, Param, ParamInfo};
+pub use self::int::float_to_int;
 pub use self::library::{set_lang_items, LangItems, Library};
 pub use self::methods::methods_on;
 pub use self::module::Module;
 pub use self::none::NoneValue;
+pub use self::rng::Rng;
 pub use self::scope::{Scope, Scopes};
 pub use self::str::{format_str, Regex, Str};
 pub use self::symbol::Symbol;
 pub use self::tracer::Tracer;
 pub use self::value::{Dynamic, Type, Value};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::mem;
 use std::path::Path;
+use std::sync::Arc;
 
 use comemo::{Track, Tracked, TrackedMut, Validate};
 use ecow::{EcoString, EcoVec};
@@ -73,9 +77,10 @@ use crate::diag::{
     bail, error, warning, At, FileError, Hint, SourceDiagnostic, SourceResult, StrResult,
     Trace, Tracepoint,
 };
+use crate::geom::{Angle, Color, Fr, Length, Ratio, Rel};
 use crate::model::{
     Content, DelayedErrors, Introspector, Label, Locator, Recipe, ShowableSelector,
-    Styles, Transform, Unlabellable, Vt,
+    Style, Styles, Transform, Unlabellable, Vt,
 };
 use crate::syntax::ast::{self, AstNode};
 use crate::syntax::{
@@ -84,7 +89,9 @@ use crate::syntax::{
 };
 use crate::World;
 
-const MAX_ITERATIONS: usize = 10_000;
+const MAX_LOOP_ITERATIONS: usize = 10_000;
+const MAX_ITERATIONS: usize = 10_000_000;
+const MAX_CALLS: usize = 10_000_000;
 const MAX_CALL_DEPTH: usize = 64;
 
 /// Evaluate a source file and return the resulting module.
@@ -129,6 +136,12 @@ pub fn eval(
         return Err(Box::new(errors.into_iter().map(Into::into).collect()));
     }
 
+    // Lint the module.
+    warn_unused_bindings(&mut vm, root);
+
+    // Check the footnote definitions.
+    footnote_names(world, id)?;
+
     // Evaluate the module.
     let markup = root.cast::<ast::Markup>().unwrap();
     let result = markup.eval(&mut vm);
@@ -143,9 +156,212 @@ pub fn eval(
     Ok(Module::new(name).with_scope(vm.scopes.top).with_content(result?))
 }
 
+/// The names of the footnotes defined in a file.
+///
+/// Footnotes can be referenced from anywhere in the file they are defined in,
+/// including the bodies of functions that are called elsewhere.
+#[comemo::memoize]
+fn footnote_names(
+    world: Tracked<dyn World + '_>,
+    id: FileId,
+) -> SourceResult<Arc<HashSet<EcoString>>> {
+    let mut names = HashSet::new();
+    if let Ok(source) = world.source(id) {
+        collect_footnotes(&mut names, source.root())?;
+    }
+    Ok(Arc::new(names))
+}
+
+/// Collect the names of the footnotes defined in the given node and its
+/// descendants.
+fn collect_footnotes(
+    names: &mut HashSet<EcoString>,
+    node: &SyntaxNode,
+) -> SourceResult<()> {
+    if let Some(def) = node.cast::<ast::FootnoteDef>() {
+        let name = EcoString::from(def.name());
+        if !names.insert(name.clone()) {
+            bail!(node.span(), "footnote `{name}` is defined multiple times");
+        }
+    }
+
+    for child in node.children() {
+        collect_footnotes(names, child)?;
+    }
+
+    Ok(())
+}
+
+/// Warn about variables in code blocks that are bound, but never read.
+///
+/// Bindings at the top level of a module are exported and thus never unused.
+/// Names starting with an underscore are exempt from the check.
+fn warn_unused_bindings(vm: &mut Vm, root: &SyntaxNode) {
+    let mut visitor = UnusedVisitor::default();
+    visitor.enter();
+    visitor.visit(root);
+
+    for (ident, read) in visitor.bindings {
+        if read || ident.is_empty() || ident.starts_with('_') {
+            continue;
+        }
+
+        vm.vt.tracer.warn(
+            warning!(ident.span(), "unused variable: {}", ident.as_str()).with_hint(
+                "prefix the name with an underscore to silence this warning".into(),
+            ),
+        );
+    }
+}
+
+/// A visitor that finds the bindings in code blocks that are never read.
+///
+/// The tree is traversed once, following the same scoping rules as the
+/// [`CapturesVisitor`]: Every read is attributed to the innermost visible
+/// binding of its name.
+#[derive(Default)]
+struct UnusedVisitor {
+    /// The names bound in each active scope. Bindings that are checked map to
+    /// their index in `bindings`.
+    scopes: Vec<HashMap<EcoString, Option<usize>>>,
+    /// The checked bindings and whether they were read.
+    bindings: Vec<(ast::Ident, bool)>,
+}
+
+impl UnusedVisitor {
+    /// Visit any node and record the reads of variables.
+    fn visit(&mut self, node: &SyntaxNode) {
+        match node.cast() {
+            Some(ast::Expr::Ident(ident)) => self.read(&ident),
+            Some(ast::Expr::MathIdent(ident)) => self.read(&ident),
+
+            // Only the bindings directly in a code block are checked.
+            Some(ast::Expr::Code(_)) => {
+                self.enter();
+                for child in node.children() {
+                    match child.cast() {
+                        Some(ast::Expr::Let(expr)) => self.visit_let(expr, true),
+                        _ => self.visit(child),
+                    }
+                }
+                self.exit();
+            }
+
+            Some(ast::Expr::Content(_)) => {
+                self.enter();
+                for child in node.children() {
+                    self.visit(child);
+                }
+                self.exit();
+            }
+
+            Some(ast::Expr::Closure(expr)) => {
+                for param in expr.params().children() {
+                    if let ast::Param::Named(named) = param {
+                        self.visit(named.expr().as_untyped());
+                    }
+                }
+
+                self.enter();
+                if let Some(name) = expr.name() {
+                    self.bind(name, false);
+                }
+
+                for param in expr.params().children() {
+                    match param {
+                        ast::Param::Pos(pattern) => {
+                            for ident in pattern.idents() {
+                                self.bind(ident, false);
+                            }
+                        }
+                        ast::Param::Named(named) => self.bind(named.name(), false),
+                        ast::Param::Typed(typed) => self.bind(typed.name(), false),
+                        ast::Param::Sink(spread) => {
+                            self.bind(spread.name().unwrap_or_default(), false)
+                        }
+                    }
+                }
+
+                self.visit(expr.body().as_untyped());
+                self.exit();
+            }
+
+            Some(ast::Expr::Let(expr)) => self.visit_let(expr, false),
+
+            Some(ast::Expr::For(expr)) => {
+                self.visit(expr.iter().as_untyped());
+                self.enter();
+                for ident in expr.pattern().idents() {
+                    self.bind(ident, false);
+                }
+                self.visit(expr.body().as_untyped());
+                self.exit();
+
+                if let Some(else_body) = expr.else_body() {
+                    self.visit(else_body.as_untyped());
+                }
+            }
+
+            Some(ast::Expr::Import(expr)) => {
+                self.visit(expr.source().as_untyped());
+                if let Some(ast::Imports::Items(items)) = expr.imports() {
+                    for item in items {
+                        self.bind(item.bound_name(), false);
+                    }
+                }
+            }
+
+            _ => {
+                for child in node.children() {
+                    self.visit(child);
+                }
+            }
+        }
+    }
+
+    /// Visit a let binding, whose names are bound after the initializer.
+    fn visit_let(&mut self, expr: ast::LetBinding, checked: bool) {
+        if let Some(init) = expr.init() {
+            self.visit(init.as_untyped());
+        }
+
+        for ident in expr.kind().idents() {
+            self.bind(ident, checked);
+        }
+    }
+
+    /// Enter a new scope.
+    fn enter(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Exit the innermost scope.
+    fn exit(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Bind a name in the innermost scope, shadowing earlier bindings.
+    fn bind(&mut self, ident: ast::Ident, checked: bool) {
+        let index = checked.then_some(self.bindings.len());
+        self.scopes.last_mut().unwrap().insert(ident.get().clone(), index);
+        if checked {
+            self.bindings.push((ident, false));
+        }
+    }
+
+    /// Mark the innermost visible binding of a name as read.
+    fn read(&mut self, name: &str) {
+        let binding = self.scopes.iter().rev().find_map(|scope| scope.get(name));
+        if let Some(&Some(index)) = binding {
+            self.bindings[index].1 = true;
+        }
+    }
+}
+
 /// Evaluate a string as code and return the resulting value.
 ///
-/// Everything in the output is associated with the given `span`.
+/// The evaluation is bounded by the given `limits`. Everything in the output
+/// is associated with the given `span`.
 #[comemo::memoize]
 pub fn eval_string(
     world: Tracked<dyn World + '_>,
@@ -153,13 +369,149 @@ pub fn eval_string(
     span: Span,
     mode: EvalMode,
     scope: Scope,
+    limits: Limits,
+) -> SourceResult<Value> {
+    let root = parse_mode(string, mode);
+    let library = world.library();
+    eval_string_impl(world, root, span, mode, scope, library, limits)
+}
+
+/// Evaluate a string containing a single code expression and return its value.
+///
+/// Everything in the output is associated with the given `span`.
+#[comemo::memoize]
+pub fn eval_expr(
+    world: Tracked<dyn World + '_>,
+    string: &str,
+    span: Span,
+    scope: Scope,
+    limits: Limits,
+) -> SourceResult<Value> {
+    let root = parse_code(string);
+    if root.errors().is_empty() {
+        match root.cast::<ast::Code>().unwrap().exprs().count() {
+            0 => bail!(span, "expected expression"),
+            1 => {}
+            _ => bail!(span, "expected a single expression"),
+        }
+    }
+
+    let mode = EvalMode::Code;
+    let library = world.library();
+    eval_string_impl(world, root, span, mode, scope, library, limits)
+}
+
+/// Parse a string containing a single literal and return its value.
+///
+/// Only pure data is accepted: Literals, arrays and dictionaries of them and
+/// negated numbers. For such values, this is the inverse of `repr`.
+///
+/// Everything in the output is associated with the given `span`.
+#[comemo::memoize]
+pub fn parse_value(
+    world: Tracked<dyn World + '_>,
+    string: &str,
+    span: Span,
+    limits: Limits,
+) -> SourceResult<Value> {
+    let root = parse_code(string);
+    if root.errors().is_empty() {
+        let mut exprs = root.cast::<ast::Code>().unwrap().exprs();
+        match (exprs.next(), exprs.next()) {
+            (None, _) => bail!(span, "expected literal"),
+            (Some(_), Some(_)) => bail!(span, "expected a single literal"),
+            (Some(expr), None) => {
+                if let Some(kind) = find_non_literal(expr) {
+                    bail!(span, "expected literal, found {}", kind.name());
+                }
+            }
+        }
+    }
+
+    let mode = EvalMode::Code;
+    let scope = Scope::new();
+    let library = world.library();
+    eval_string_impl(world, root, span, mode, scope, library, limits)
+}
+
+/// Find the kind of the first expression that is not pure data.
+fn find_non_literal(expr: ast::Expr) -> Option<SyntaxKind> {
+    match expr {
+        ast::Expr::None(_)
+        | ast::Expr::Auto(_)
+        | ast::Expr::Bool(_)
+        | ast::Expr::Int(_)
+        | ast::Expr::Float(_)
+        | ast::Expr::Numeric(_)
+        | ast::Expr::Str(_)
+        | ast::Expr::Label(_) => None,
+        ast::Expr::Unary(unary)
+            if unary.op() != ast::UnOp::Not
+                && matches!(
+                    unary.expr(),
+                    ast::Expr::Int(_) | ast::Expr::Float(_) | ast::Expr::Numeric(_)
+                ) =>
+        {
+            None
+        }
+        ast::Expr::Parenthesized(parenthesized) => find_non_literal(parenthesized.expr()),
+        ast::Expr::Array(array) => array.items().find_map(|item| match item {
+            ast::ArrayItem::Pos(expr) => find_non_literal(expr),
+            ast::ArrayItem::Spread(_) => Some(SyntaxKind::Spread),
+        }),
+        ast::Expr::Dict(dict) => dict.items().find_map(|item| match item {
+            ast::DictItem::Named(named) => find_non_literal(named.expr()),
+            ast::DictItem::Keyed(keyed) => find_non_literal(keyed.expr()),
+            ast::DictItem::Spread(_) => Some(SyntaxKind::Spread),
+        }),
+        expr => Some(expr.as_untyped().kind()),
+    }
+}
+
+/// Evaluate an untrusted string in a sandbox and return the resulting value.
+///
+/// Instead of the world's standard library, the code only sees the
+/// definitions in the given `library`, so that functions like `read`,
+/// `locate` or `query` can be withheld by leaving them out of it. The code
+/// cannot import or include files or packages. Its function call depth is
+/// bounded by the given `limits`, just like its loop iterations and function
+/// calls, which are counted across all functions the code calls.
+///
+/// Everything in the output is associated with the given `span`.
+#[comemo::memoize]
+pub fn eval_sandboxed(
+    world: Tracked<dyn World + '_>,
+    string: &str,
+    span: Span,
+    mode: EvalMode,
+    scope: Scope,
+    library: &Library,
+    limits: Limits,
 ) -> SourceResult<Value> {
-    let mut root = match mode {
+    let root = parse_mode(string, mode);
+    let limits = Limits { files: false, ..limits };
+    eval_string_impl(world, root, span, mode, scope, library, limits)
+}
+
+/// Parse a string in the given mode.
+fn parse_mode(string: &str, mode: EvalMode) -> SyntaxNode {
+    match mode {
         EvalMode::Code => parse_code(string),
         EvalMode::Markup => parse(string),
         EvalMode::Math => parse_math(string),
-    };
+    }
+}
 
+/// The shared implementation of the string evaluation functions.
+fn eval_string_impl(
+    world: Tracked<dyn World + '_>,
+    mut root: SyntaxNode,
+    span: Span,
+    mode: EvalMode,
+    scope: Scope,
+    library: &Library,
+    limits: Limits,
+) -> SourceResult<Value> {
     root.synthesize(span);
 
     let errors = root.errors();
@@ -183,9 +535,11 @@ pub fn eval_string(
     // Prepare VM.
     let route = Route::default();
     let id = FileId::detached();
-    let scopes = Scopes::new(Some(world.library()));
+    let scopes = Scopes::new(Some(library));
     let mut vm = Vm::new(vt, route.track(), id, scopes);
     vm.scopes.scopes.push(scope);
+    vm.limits = limits;
+    vm.rng = Rng::new(library.seed);
 
     // Evaluate the code.
     let result = match mode {
@@ -217,6 +571,53 @@ pub enum EvalMode {
     Math,
 }
 
+/// Bounds on the resources an evaluation may use.
+///
+/// The limits are inherited by all closures called during the evaluation and
+/// by the closures it creates, even when they are called later, for example
+/// as show rules. Documents are evaluated with the limits of the world's
+/// [`Library`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Limits {
+    /// The maximum function call depth.
+    pub depth: usize,
+    /// The maximum number of loop iterations of an evaluation.
+    ///
+    /// The iterations performed by called closures count towards their
+    /// callers. A call is bounded by the full limit on its own, but once it
+    /// returns, what it used is added to its caller's count, which then fails
+    /// if its own limit is exceeded.
+    pub iterations: usize,
+    /// The maximum number of closure calls of an evaluation, counted in the
+    /// same way as the loop iterations.
+    pub calls: usize,
+    /// Whether files and packages may be imported and included.
+    pub files: bool,
+}
+
+impl Limits {
+    /// The stricter of two sets of limits.
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            depth: self.depth.min(other.depth),
+            iterations: self.iterations.min(other.iterations),
+            calls: self.calls.min(other.calls),
+            files: self.files && other.files,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            depth: MAX_CALL_DEPTH,
+            iterations: MAX_ITERATIONS,
+            calls: MAX_CALLS,
+            files: true,
+        }
+    }
+}
+
 /// A virtual machine.
 ///
 /// Holds the state needed to [evaluate](eval) Typst sources. A new
@@ -236,8 +637,25 @@ pub struct Vm<'a> {
     scopes: Scopes<'a>,
     /// The current call depth.
     depth: usize,
+    /// The resource limits of the evaluation.
+    limits: Limits,
+    /// The number of loop iterations performed so far, including those within
+    /// returned calls.
+    iterations: usize,
+    /// The number of closure calls performed so far, including those within
+    /// returned calls.
+    calls: usize,
+    /// The number of loops the evaluation is currently nested in.
+    loops: usize,
+    /// Whether the innermost block is a code block.
+    code: bool,
+    /// The closure whose body is currently evaluated.
+    this: Option<Func>,
     /// A span that is currently traced.
     traced: Option<Span>,
+    /// The random number generator, which is passed on to called closures
+    /// and continued from their state once they return.
+    rng: Rng,
 }
 
 impl<'a> Vm<'a> {
@@ -249,7 +667,10 @@ impl<'a> Vm<'a> {
         scopes: Scopes<'a>,
     ) -> Self {
         let traced = vt.tracer.span(location);
-        let items = vt.world.library().items.clone();
+        let library = vt.world.library();
+        let items = library.items.clone();
+        let limits = library.limits;
+        let rng = Rng::new(library.seed);
         Self {
             vt,
             items,
@@ -258,7 +679,14 @@ impl<'a> Vm<'a> {
             flow: None,
             scopes,
             depth: 0,
+            limits,
+            iterations: 0,
+            calls: 0,
+            loops: 0,
+            code: false,
+            this: None,
             traced,
+            rng,
         }
     }
 
@@ -272,6 +700,23 @@ impl<'a> Vm<'a> {
         self.location
     }
 
+    /// The resource limits of this evaluation, with what is left of its
+    /// budgets.
+    ///
+    /// Evaluations of strings started by this one are bounded by these limits.
+    pub fn limits(&self) -> Limits {
+        Limits {
+            iterations: self.limits.iterations.saturating_sub(self.iterations),
+            calls: self.limits.calls.saturating_sub(self.calls),
+            ..self.limits
+        }
+    }
+
+    /// The random number generator of this evaluation.
+    pub fn rng(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
     /// Define a variable in the current scope.
     #[tracing::instrument(skip_all)]
     pub fn define(&mut self, var: ast::Ident, value: impl IntoValue) {
@@ -281,6 +726,51 @@ impl<'a> Vm<'a> {
         }
         self.scopes.top.define(var.take(), value);
     }
+
+    /// Define a variable through a `let` binding, warning if it shadows a
+    /// binding of the same code block.
+    fn define_let(&mut self, var: ast::Ident, value: impl IntoValue) {
+        let span = var.span();
+        let name = var.get().clone();
+        let shadowed = self.scopes.top.get(&name).is_some();
+        self.define(var, value);
+        if shadowed && self.code && !name.starts_with('_') {
+            let hint = eco_format!("use `{name} = ...` to update the existing variable");
+            self.vt
+                .tracer
+                .warn(warning!(span, "shadowed variable: {}", name).with_hint(hint));
+        }
+    }
+
+    /// Count a loop iteration, failing if the iteration limit is exceeded.
+    fn iterate(&mut self, span: Span) -> SourceResult<()> {
+        self.spend(1, 0, span)
+    }
+
+    /// Count a closure call, failing if the call limit is exceeded.
+    fn count_call(&mut self, span: Span) -> SourceResult<()> {
+        self.spend(0, 1, span)
+    }
+
+    /// Count loop iterations and closure calls, like those performed by a
+    /// returned call, failing if a limit is exceeded.
+    fn spend(&mut self, iterations: usize, calls: usize, span: Span) -> SourceResult<()> {
+        self.iterations += iterations;
+        self.calls += calls;
+        if self.iterations > self.limits.iterations {
+            bail!(SourceDiagnostic::fatal(
+                span,
+                "maximum number of loop iterations exceeded"
+            ));
+        }
+        if self.calls > self.limits.calls {
+            bail!(SourceDiagnostic::fatal(
+                span,
+                "maximum number of function calls exceeded"
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// A control flow event that occurred during evaluation.
@@ -293,6 +783,10 @@ pub enum FlowEvent {
     /// Stop execution of a function early, optionally returning an explicit
     /// value.
     Return(Span, Option<Value>),
+    /// Evaluate the body of the current closure again with new arguments
+    /// instead of recursing. Holds the value of the enclosing `return`, if
+    /// the self-call was made from one.
+    TailCall(Span, Args, Option<Value>),
 }
 
 impl FlowEvent {
@@ -308,6 +802,9 @@ impl FlowEvent {
             Self::Return(span, _) => {
                 error!(span, "cannot return outside of function")
             }
+            Self::TailCall(span, ..) => {
+                error!(span, "cannot call function in tail position here")
+            }
         }
     }
 }
@@ -374,42 +871,77 @@ impl Eval for ast::Markup {
 }
 
 /// Evaluate a stream of markup.
+///
+/// Errors in expressions that don't define anything can't affect their
+/// siblings. Therefore, evaluation continues after them so that all of them
+/// can be reported at once. Errors in bindings, imports, and rules are fatal.
 fn eval_markup(
     vm: &mut Vm,
     exprs: &mut impl Iterator<Item = ast::Expr>,
 ) -> SourceResult<Content> {
     let flow = vm.flow.take();
     let mut seq = Vec::with_capacity(exprs.size_hint().1.unwrap_or_default());
+    let mut errors = vec![];
+
+    // Adds the errors collected so far to a fatal error.
+    let fatal = |errors: &mut Vec<SourceDiagnostic>, error: Box<Vec<_>>| {
+        errors.extend(*error);
+        Box::new(mem::take(errors))
+    };
 
     while let Some(expr) = exprs.next() {
         match expr {
             ast::Expr::Set(set) => {
-                let styles = set.eval(vm)?;
+                let styles = set.eval(vm).map_err(|err| fatal(&mut errors, err))?;
                 if vm.flow.is_some() {
                     break;
                 }
 
-                seq.push(eval_markup(vm, exprs)?.styled_with_map(styles))
+                let tail =
+                    eval_markup(vm, exprs).map_err(|err| fatal(&mut errors, err))?;
+                seq.push(tail.styled_with_map(styles))
             }
             ast::Expr::Show(show) => {
-                let recipe = show.eval(vm)?;
+                let recipe = show.eval(vm).map_err(|err| fatal(&mut errors, err))?;
                 if vm.flow.is_some() {
                     break;
                 }
 
-                let tail = eval_markup(vm, exprs)?;
-                seq.push(tail.styled_with_recipe(vm, recipe)?)
+                let tail =
+                    eval_markup(vm, exprs).map_err(|err| fatal(&mut errors, err))?;
+                seq.push(
+                    tail.styled_with_recipe(vm, recipe)
+                        .map_err(|err| fatal(&mut errors, err))?,
+                )
             }
-            expr => match expr.eval(vm)? {
-                Value::Label(label) => {
-                    if let Some(elem) =
-                        seq.iter_mut().rev().find(|node| !node.can::<dyn Unlabellable>())
-                    {
-                        *elem = mem::take(elem).labelled(label);
+            expr => {
+                let checkpoint = Checkpoint::new(vm);
+                match expr.eval(vm) {
+                    Ok(Value::Label(label)) => {
+                        if let Some(elem) = seq
+                            .iter_mut()
+                            .rev()
+                            .find(|node| !node.can::<dyn Unlabellable>())
+                        {
+                            *elem = mem::take(elem).labelled(label);
+                        } else {
+                            let span = expr.span();
+                            let hint = "labels attach to the preceding element \
+                                        in the same content block";
+                            vm.vt.tracer.warn(
+                                warning!(span, "label is not attached to anything")
+                                    .with_hint(hint.into()),
+                            );
+                        }
+                    }
+                    Ok(value) => seq.push(value.display().spanned(expr.span())),
+                    Err(err) if is_recoverable(&expr, &err) => {
+                        checkpoint.restore(vm);
+                        errors.extend(*err);
                     }
+                    Err(err) => return Err(fatal(&mut errors, err)),
                 }
-                value => seq.push(value.display().spanned(expr.span())),
-            },
+            }
         }
 
         if vm.flow.is_some() {
@@ -421,6 +953,10 @@ fn eval_markup(
         vm.flow = flow;
     }
 
+    if !errors.is_empty() {
+        return Err(Box::new(errors));
+    }
+
     Ok(Content::sequence(seq))
 }
 
@@ -448,6 +984,8 @@ impl Eval for ast::Expr {
             Self::Link(v) => v.eval(vm).map(Value::Content),
             Self::Label(v) => v.eval(vm),
             Self::Ref(v) => v.eval(vm).map(Value::Content),
+            Self::FootnoteRef(v) => v.eval(vm).map(Value::Content),
+            Self::FootnoteDef(v) => v.eval(vm).map(Value::Content),
             Self::Heading(v) => v.eval(vm).map(Value::Content),
             Self::List(v) => v.eval(vm).map(Value::Content),
             Self::Enum(v) => v.eval(vm).map(Value::Content),
@@ -481,8 +1019,16 @@ impl Eval for ast::Expr {
             Self::Binary(v) => v.eval(vm),
             Self::Let(v) => v.eval(vm),
             Self::DestructAssign(v) => v.eval(vm),
-            Self::Set(_) => bail!(forbidden("set")),
-            Self::Show(_) => bail!(forbidden("show")),
+            Self::Set(v) => v.eval(vm).map(Value::Styles),
+            Self::Show(v) => {
+                let recipe = v.eval(vm)?;
+                if recipe.selector.is_none() {
+                    bail!(forbidden("show").with_hint(
+                        "only show rules with a selector can be used as values".into()
+                    ));
+                }
+                Ok(Value::Styles(Style::from(recipe).into()))
+            }
             Self::Conditional(v) => v.eval(vm),
             Self::While(v) => v.eval(vm),
             Self::For(v) => v.eval(vm),
@@ -544,7 +1090,7 @@ impl Eval for ast::Parbreak {
 
     #[tracing::instrument(name = "Parbreak::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        Ok((vm.items.parbreak)())
+        Ok((vm.items.parbreak)(self.blank_lines() >= 2))
     }
 }
 
@@ -609,9 +1155,21 @@ impl Eval for ast::Raw {
 
     #[tracing::instrument(name = "Raw::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let text = self.text();
-        let lang = self.lang().map(Into::into);
+        let mut text = self.text();
+        let mut lang = self.lang().map(Into::into);
         let block = self.block();
+
+        // Inline raw text only has a language if the tag is a known one.
+        if let Some(tag) = self.inline_lang() {
+            let known = (vm.items.raw_languages)().iter().any(|(name, tags)| {
+                name.eq_ignore_ascii_case(tag) || tags.contains(&tag)
+            });
+            if known {
+                text = text[tag.len() + 2..].into();
+                lang = Some(tag.into());
+            }
+        }
+
         Ok((vm.items.raw)(text, lang, block))
     }
 }
@@ -645,6 +1203,34 @@ impl Eval for ast::Ref {
     }
 }
 
+impl Eval for ast::FootnoteRef {
+    type Output = Content;
+
+    #[tracing::instrument(name = "FootnoteRef::eval", skip_all)]
+    fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        // Which reference carries the note is only known during layout, when
+        // the references of the whole document are in order.
+        let name = self.get();
+        if !footnote_names(vm.world(), vm.location)?.contains(name) {
+            bail!(error!(self.span(), "footnote `{}` is not defined", name).with_hint(
+                eco_format!("define it on its own line with `[^{name}]: ...`")
+            ));
+        }
+
+        Ok((vm.items.footnote)(name.into()))
+    }
+}
+
+impl Eval for ast::FootnoteDef {
+    type Output = Content;
+
+    #[tracing::instrument(name = "FootnoteDef::eval", skip_all)]
+    fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        let body = self.body().eval(vm)?;
+        Ok((vm.items.footnote_def)(self.name().into(), body))
+    }
+}
+
 impl Eval for ast::Heading {
     type Output = Content;
 
@@ -661,7 +1247,9 @@ impl Eval for ast::ListItem {
 
     #[tracing::instrument(name = "ListItem::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        Ok((vm.items.list_item)(self.body().eval(vm)?))
+        let checked = self.checked();
+        let body = self.body().eval(vm)?;
+        Ok((vm.items.list_item)(checked, body))
     }
 }
 
@@ -867,10 +1455,12 @@ impl Eval for ast::CodeBlock {
 
     #[tracing::instrument(name = "CodeBlock::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        let code = mem::replace(&mut vm.code, true);
         vm.scopes.enter();
-        let output = self.body().eval(vm)?;
+        let output = self.body().eval(vm);
         vm.scopes.exit();
-        Ok(output)
+        vm.code = code;
+        output
     }
 }
 
@@ -883,38 +1473,66 @@ impl Eval for ast::Code {
 }
 
 /// Evaluate a stream of expressions.
+///
+/// Like in markup, evaluation continues after errors in expressions that don't
+/// define anything.
 fn eval_code(
     vm: &mut Vm,
     exprs: &mut impl Iterator<Item = ast::Expr>,
 ) -> SourceResult<Value> {
     let flow = vm.flow.take();
     let mut output = Value::None;
+    let mut errors = vec![];
+
+    // Adds the errors collected so far to a fatal error.
+    let fatal = |errors: &mut Vec<SourceDiagnostic>, error: Box<Vec<_>>| {
+        errors.extend(*error);
+        Box::new(mem::take(errors))
+    };
 
     while let Some(expr) = exprs.next() {
         let span = expr.span();
         let value = match expr {
             ast::Expr::Set(set) => {
-                let styles = set.eval(vm)?;
+                let styles = set.eval(vm).map_err(|err| fatal(&mut errors, err))?;
                 if vm.flow.is_some() {
                     break;
                 }
 
-                let tail = eval_code(vm, exprs)?.display();
-                Value::Content(tail.styled_with_map(styles))
+                let tail = eval_code(vm, exprs).map_err(|err| fatal(&mut errors, err))?;
+                Value::Content(tail.display().styled_with_map(styles))
             }
             ast::Expr::Show(show) => {
-                let recipe = show.eval(vm)?;
+                let recipe = show.eval(vm).map_err(|err| fatal(&mut errors, err))?;
                 if vm.flow.is_some() {
                     break;
                 }
 
-                let tail = eval_code(vm, exprs)?.display();
-                Value::Content(tail.styled_with_recipe(vm, recipe)?)
+                let tail = eval_code(vm, exprs).map_err(|err| fatal(&mut errors, err))?;
+                Value::Content(
+                    tail.display()
+                        .styled_with_recipe(vm, recipe)
+                        .map_err(|err| fatal(&mut errors, err))?,
+                )
+            }
+            expr => {
+                let checkpoint = Checkpoint::new(vm);
+                match expr.eval(vm) {
+                    Ok(value) => value,
+                    Err(err) if is_recoverable(&expr, &err) => {
+                        checkpoint.restore(vm);
+                        errors.extend(*err);
+                        continue;
+                    }
+                    Err(err) => return Err(fatal(&mut errors, err)),
+                }
             }
-            _ => expr.eval(vm)?,
         };
 
-        output = ops::join(output, value).at(span)?;
+        match ops::join(mem::take(&mut output), value).at(span) {
+            Ok(joined) => output = joined,
+            Err(err) => errors.extend(*err),
+        }
 
         if vm.flow.is_some() {
             break;
@@ -925,18 +1543,69 @@ fn eval_code(
         vm.flow = flow;
     }
 
+    if !errors.is_empty() {
+        return Err(Box::new(errors));
+    }
+
     Ok(output)
 }
 
+/// Whether evaluation can continue after the given errors in an expression.
+///
+/// This is the case if the expression doesn't define anything that later
+/// expressions could depend on and none of the errors is fatal. Continuing
+/// after an exceeded resource limit would let the evaluation go on using
+/// resources.
+fn is_recoverable(expr: &ast::Expr, errors: &[SourceDiagnostic]) -> bool {
+    !matches!(
+        expr,
+        ast::Expr::Let(_) | ast::Expr::DestructAssign(_) | ast::Expr::Import(_)
+    ) && !errors.iter().any(|error| error.fatal)
+}
+
+/// The parts of the VM's state that a failed expression may leave behind.
+struct Checkpoint {
+    /// The number of scopes.
+    scopes: usize,
+    /// The number of loops the evaluation is nested in.
+    loops: usize,
+    /// Whether the innermost block is a code block.
+    code: bool,
+}
+
+impl Checkpoint {
+    /// Remember the current state of the VM.
+    fn new(vm: &Vm) -> Self {
+        Self {
+            scopes: vm.scopes.scopes.len(),
+            loops: vm.loops,
+            code: vm.code,
+        }
+    }
+
+    /// Return the VM to the remembered state, leaving the scopes and loops
+    /// the failed expression didn't exit.
+    fn restore(self, vm: &mut Vm) {
+        while vm.scopes.scopes.len() > self.scopes {
+            vm.scopes.exit();
+        }
+        vm.loops = self.loops;
+        vm.code = self.code;
+        vm.flow = None;
+    }
+}
+
 impl Eval for ast::ContentBlock {
     type Output = Content;
 
     #[tracing::instrument(name = "ContentBlock::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        let code = mem::replace(&mut vm.code, false);
         vm.scopes.enter();
-        let content = self.body().eval(vm)?;
+        let content = self.body().eval(vm);
         vm.scopes.exit();
-        Ok(content)
+        vm.code = code;
+        content
     }
 }
 
@@ -985,7 +1654,11 @@ impl Eval for ast::Dict {
                     map.insert(named.name().take().into(), named.expr().eval(vm)?);
                 }
                 ast::DictItem::Keyed(keyed) => {
-                    map.insert(keyed.key().get().into(), keyed.expr().eval(vm)?);
+                    let key = match keyed.key() {
+                        ast::Expr::Int(int) => DictKey::from(int.get()).0,
+                        key => key.eval(vm)?.cast::<Str>().at(key.span())?,
+                    };
+                    map.insert(key, keyed.expr().eval(vm)?);
                 }
                 ast::DictItem::Spread(expr) => match expr.eval(vm)? {
                     Value::None => {}
@@ -1043,6 +1716,8 @@ impl Eval for ast::Binary {
             ast::BinOp::SubAssign => apply_assignment(self, vm, ops::sub),
             ast::BinOp::MulAssign => apply_assignment(self, vm, ops::mul),
             ast::BinOp::DivAssign => apply_assignment(self, vm, ops::div),
+            ast::BinOp::AndAssign => apply_logical_assignment(self, vm, ops::and),
+            ast::BinOp::OrAssign => apply_logical_assignment(self, vm, ops::or),
         }
     }
 }
@@ -1079,8 +1754,10 @@ fn apply_assignment(
     // since it can create the field instead of just modifying it.
     if binary.op() == ast::BinOp::Assign {
         if let ast::Expr::FieldAccess(access) = &lhs {
-            let dict = access_dict(vm, access)?;
-            dict.insert(access.field().take().into(), rhs);
+            match access.target().access(vm)? {
+                Value::Dict(dict) => dict.insert(access.field().take().into(), rhs),
+                target => *access_field(target, access)? = rhs,
+            }
             return Ok(Value::None);
         }
     }
@@ -1091,12 +1768,37 @@ fn apply_assignment(
     Ok(Value::None)
 }
 
+/// Apply a short-circuiting logical assignment.
+fn apply_logical_assignment(
+    binary: &ast::Binary,
+    vm: &mut Vm,
+    op: fn(Value, Value) -> StrResult<Value>,
+) -> SourceResult<Value> {
+    let lhs = binary.lhs().access(vm)?.clone();
+
+    // Don't evaluate the right-hand side if the result is already known.
+    if (binary.op() == ast::BinOp::AndAssign && lhs == Value::Bool(false))
+        || (binary.op() == ast::BinOp::OrAssign && lhs == Value::Bool(true))
+    {
+        return Ok(Value::None);
+    }
+
+    let rhs = binary.rhs().eval(vm)?;
+    let location = binary.lhs().access(vm)?;
+    *location = op(lhs, rhs).at(binary.span())?;
+    Ok(Value::None)
+}
+
 impl Eval for ast::FieldAccess {
     type Output = Value;
 
     #[tracing::instrument(name = "FieldAccess::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let value = self.target().eval(vm)?;
+        if matches!(value, Value::None) && self.optional() {
+            return Ok(Value::None);
+        }
+
         let field = self.field();
         value.field(&field).at(field.span())
     }
@@ -1107,102 +1809,150 @@ impl Eval for ast::FuncCall {
 
     #[tracing::instrument(name = "FuncCall::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let span = self.span();
-        if vm.depth >= MAX_CALL_DEPTH {
-            bail!(span, "maximum function call depth exceeded");
-        }
-
-        let callee = self.callee();
-        let in_math = in_math(&callee);
-        let callee_span = callee.span();
-        let args = self.args();
-
-        // Try to evaluate as a method call. This is possible if the callee is a
-        // field access and does not evaluate to a module.
-        let (callee, mut args) = if let ast::Expr::FieldAccess(access) = callee {
-            let target = access.target();
-            let field = access.field();
-            let field_span = field.span();
-            let field = field.take();
-            let point = || Tracepoint::Call(Some(field.clone()));
-            if methods::is_mutating(&field) {
-                let args = args.eval(vm)?;
-                let target = target.access(vm)?;
-
-                // Prioritize a function's own methods (with, where) over its
-                // fields. This is fine as we define each field of a function,
-                // if it has any.
-                // ('methods_on' will be empty for Symbol and Module - their
-                // method calls always refer to their fields.)
-                if !matches!(target, Value::Symbol(_) | Value::Module(_) | Value::Func(_))
-                    || methods_on(target.type_name()).iter().any(|(m, _)| m == &field)
-                {
-                    return methods::call_mut(target, &field, args, span).trace(
-                        vm.world(),
-                        point,
-                        span,
-                    );
-                }
-                (target.field(&field).at(field_span)?, args)
-            } else {
-                let target = target.eval(vm)?;
-                let args = args.eval(vm)?;
-
-                if !matches!(target, Value::Symbol(_) | Value::Module(_) | Value::Func(_))
-                    || methods_on(target.type_name()).iter().any(|(m, _)| m == &field)
-                {
-                    return methods::call(vm, target, &field, args, span).trace(
-                        vm.world(),
-                        point,
-                        span,
-                    );
-                }
-                (target.field(&field).at(field_span)?, args)
+        eval_call(vm, self, None)
+    }
+}
+
+/// Evaluate a function call.
+///
+/// If `reversed` is given and the call is a call to `rev()` on an array or
+/// string, the target is returned as is and `reversed` is set to `true`.
+fn eval_call(
+    vm: &mut Vm,
+    call: &ast::FuncCall,
+    reversed: Option<&mut bool>,
+) -> SourceResult<Value> {
+    let span = call.span();
+    if vm.depth >= vm.limits.depth {
+        bail!(SourceDiagnostic::fatal(span, "maximum function call depth exceeded"));
+    }
+
+    let callee = call.callee();
+    let in_math = in_math(&callee);
+    let callee_span = callee.span();
+    let args = call.args();
+
+    // Try to evaluate as a method call. This is possible if the callee is a
+    // field access and does not evaluate to a module.
+    let (callee, mut args) = if let ast::Expr::FieldAccess(access) = callee {
+        let target = access.target();
+        let field = access.field();
+        let field_span = field.span();
+        let field = field.take();
+        let point = || Tracepoint::Call(Some(field.clone()));
+        if methods::is_mutating(&field) {
+            let args = args.eval(vm)?;
+            let target = target.access(vm)?;
+
+            // Prioritize a function's own methods (with, where) over its
+            // fields. This is fine as we define each field of a function,
+            // if it has any.
+            // ('methods_on' will be empty for Symbol and Module - their
+            // method calls always refer to their fields.)
+            if !matches!(target, Value::Symbol(_) | Value::Module(_) | Value::Func(_))
+                || methods_on(target.type_name()).iter().any(|(m, _)| m == &field)
+            {
+                return methods::call_mut(target, &field, args, span).trace(
+                    vm.world(),
+                    point,
+                    span,
+                );
             }
+            (target.field(&field).at(field_span)?, args)
         } else {
-            (callee.eval(vm)?, args.eval(vm)?)
-        };
+            let target = target.eval(vm)?;
+            if matches!(target, Value::None) && access.optional() {
+                return Ok(Value::None);
+            }
+
+            let args = args.eval(vm)?;
 
-        // Handle math special cases for non-functions:
-        // Combining accent symbols apply themselves while everything else
-        // simply displays the arguments verbatim.
-        if in_math && !matches!(callee, Value::Func(_)) {
-            if let Value::Symbol(sym) = &callee {
-                let c = sym.get();
-                if let Some(accent) = Symbol::combining_accent(c) {
-                    let base = args.expect("base")?;
-                    args.finish()?;
-                    return Ok(Value::Content((vm.items.math_accent)(base, accent)));
+            if !matches!(target, Value::Symbol(_) | Value::Module(_) | Value::Func(_))
+                || methods_on(target.type_name()).iter().any(|(m, _)| m == &field)
+            {
+                // Let a for loop iterate backwards over the target instead of
+                // materializing a reversed copy.
+                if let Some(reversed) = reversed {
+                    if field.as_str() == "rev"
+                        && args.items.is_empty()
+                        && matches!(target, Value::Array(_) | Value::Str(_))
+                    {
+                        *reversed = true;
+                        return Ok(target);
+                    }
                 }
+
+                return methods::call(vm, target, &field, args, span).trace(
+                    vm.world(),
+                    point,
+                    span,
+                );
             }
-            let mut body = Content::empty();
-            for (i, arg) in args.all::<Content>()?.into_iter().enumerate() {
-                if i > 0 {
-                    body += (vm.items.text)(','.into());
-                }
-                body += arg;
-            }
-            return Ok(Value::Content(
-                callee.display().spanned(callee_span)
-                    + (vm.items.math_delimited)(
-                        (vm.items.text)('('.into()),
-                        body,
-                        (vm.items.text)(')'.into()),
-                    ),
-            ));
+            (target.field(&field).at(field_span)?, args)
         }
+    } else {
+        (callee.eval(vm)?, args.eval(vm)?)
+    };
 
-        let callee = callee.cast::<Func>().at(callee_span)?;
-        let point = || Tracepoint::Call(callee.name().map(Into::into));
-        let f = || callee.call_vm(vm, args).trace(vm.world(), point, span);
+    // Handle math special cases for non-functions:
+    // Combining accent symbols apply themselves while everything else
+    // simply displays the arguments verbatim.
+    if in_math && !matches!(callee, Value::Func(_)) {
+        if let Value::Symbol(sym) = &callee {
+            let c = sym.get();
+            if let Some(accent) = Symbol::combining_accent(c) {
+                let base = args.expect("base")?;
+                args.finish()?;
+                return Ok(Value::Content((vm.items.math_accent)(base, accent)));
+            }
+        }
+        let mut body = Content::empty();
+        for (i, arg) in args.all::<Content>()?.into_iter().enumerate() {
+            if i > 0 {
+                body += (vm.items.text)(','.into());
+            }
+            body += arg;
+        }
+        return Ok(Value::Content(
+            callee.display().spanned(callee_span)
+                + (vm.items.math_delimited)(
+                    (vm.items.text)('('.into()),
+                    body,
+                    (vm.items.text)(')'.into()),
+                ),
+        ));
+    }
 
-        // Stacker is broken on WASM.
-        #[cfg(target_arch = "wasm32")]
-        return f();
+    let callee = callee.cast::<Func>().at(callee_span)?;
+    if let Some(message) = callee.deprecation() {
+        let warning = match call.callee() {
+            ast::Expr::Ident(ident) => {
+                warning!(span, "`{}` is deprecated", ident.as_str())
+            }
+            ast::Expr::FieldAccess(access) => {
+                warning!(span, "`{}` is deprecated", access.field().as_str())
+            }
+            _ => warning!(span, "function is deprecated"),
+        };
+        vm.vt.tracer.warn(warning.with_hint(message.into()));
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        stacker::maybe_grow(32 * 1024, 2 * 1024 * 1024, f)
+    // Let the closure loop instead of recursing for self-calls in tail
+    // position.
+    if matches!(&vm.this, Some(this) if this.is_tail_call(span) && *this == callee) {
+        vm.flow = Some(FlowEvent::TailCall(span, args, None));
+        return Ok(Value::None);
     }
+
+    let point = || Tracepoint::Call(callee.name().map(Into::into));
+    let f = || callee.call_vm(vm, args).trace(vm.world(), point, span);
+
+    // Stacker is broken on WASM.
+    #[cfg(target_arch = "wasm32")]
+    return f();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    stacker::maybe_grow(32 * 1024, 2 * 1024 * 1024, f)
 }
 
 fn in_math(expr: &ast::Expr) -> bool {
@@ -1285,17 +2035,40 @@ impl Eval for ast::Closure {
                 ast::Param::Named(named) => {
                     params.push(Param::Named(named.name(), named.expr().eval(vm)?));
                 }
-                ast::Param::Sink(spread) => params.push(Param::Sink(spread.name())),
+                ast::Param::Typed(typed) => {
+                    let ty = typed.annotation().name();
+                    if !is_type_name(&ty) {
+                        bail!(ty.span(), "unknown type: {}", ty.as_str());
+                    }
+                    params.push(Param::Typed(typed.name(), ty));
+                }
+                ast::Param::Sink(spread) => {
+                    // A second sink collects the remaining named arguments.
+                    if params.iter().any(|param| matches!(param, Param::Sink(_))) {
+                        params.push(Param::NamedSink(spread.name()));
+                    } else {
+                        params.push(Param::Sink(spread.name()));
+                    }
+                }
             }
         }
 
+        // Find self-calls that can be evaluated without recursing.
+        let body = self.body();
+        let tail_calls = match &name {
+            Some(name) => func::tail_calls(name, &body),
+            None => vec![],
+        };
+
         // Define the closure.
         let closure = Closure {
             location: vm.location,
+            limits: vm.limits,
             name,
             captured,
             params,
-            body: self.body(),
+            body,
+            tail_calls,
         };
 
         Ok(Value::Func(Func::from(closure).spanned(self.params().span())))
@@ -1463,21 +2236,83 @@ impl Eval for ast::LetBinding {
 
     #[tracing::instrument(name = "LetBinding::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let value = match self.init() {
+        let mut value = match self.init() {
             Some(expr) => expr.eval(vm)?,
             None => Value::None,
         };
 
+        if let Some(annotation) = self.annotation() {
+            let name = annotation.name();
+            let span = self.init().map_or(annotation.span(), |init| init.span());
+            value = match cast_to_type(&name, value) {
+                Some(result) => result.at(span)?,
+                None => bail!(name.span(), "unknown type: {}", name.as_str()),
+            };
+        }
+
         match self.kind() {
-            ast::LetBindingKind::Normal(pattern) => define_pattern(vm, &pattern, value),
+            ast::LetBindingKind::Normal(pattern) => {
+                destructure(vm, &pattern, value, |vm, expr, value| match expr {
+                    ast::Expr::Ident(ident) => {
+                        vm.define_let(ident, value);
+                        Ok(Value::None)
+                    }
+                    _ => {
+                        bail!(expr.span(), "nested patterns are currently not supported")
+                    }
+                })
+            }
             ast::LetBindingKind::Closure(ident) => {
-                vm.define(ident, value);
+                vm.define_let(ident, value);
                 Ok(Value::None)
             }
         }
     }
 }
 
+/// Cast a value to the type with the given name, as used by type annotations.
+///
+/// Returns `None` if there is no type with this name.
+fn cast_to_type(name: &str, value: Value) -> Option<StrResult<Value>> {
+    macro_rules! cast_to {
+        ($($name:literal: $ty:ty),* $(,)?) => {
+            match name {
+                $($name => Some(value.cast::<$ty>().map(IntoValue::into_value)),)*
+                _ => None,
+            }
+        };
+    }
+
+    cast_to! {
+        "any": Value,
+        "bool": bool,
+        "int": i64,
+        "float": f64,
+        "length": Length,
+        "angle": Angle,
+        "ratio": Ratio,
+        "relative": Rel<Length>,
+        "fraction": Fr,
+        "color": Color,
+        "symbol": Symbol,
+        "str": Str,
+        "bytes": Bytes,
+        "label": Label,
+        "content": Content,
+        "array": Array,
+        "dictionary": Dict,
+        "function": Func,
+        "arguments": Args,
+        "module": Module,
+        "datetime": Datetime,
+    }
+}
+
+/// Whether the given name can be used in a type annotation.
+fn is_type_name(name: &str) -> bool {
+    cast_to_type(name, Value::None).is_some()
+}
+
 impl Eval for ast::DestructAssignment {
     type Output = Value;
 
@@ -1558,27 +2393,32 @@ impl Eval for ast::WhileLoop {
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let flow = vm.flow.take();
         let mut output = Value::None;
+        let mut broken = false;
         let mut i = 0;
 
         let condition = self.condition();
         let body = self.body();
 
+        vm.loops += 1;
         while condition.eval(vm)?.cast::<bool>().at(condition.span())? {
             if i == 0
                 && is_invariant(condition.as_untyped())
                 && !can_diverge(body.as_untyped())
             {
                 bail!(condition.span(), "condition is always true");
-            } else if i >= MAX_ITERATIONS {
+            } else if i >= MAX_LOOP_ITERATIONS {
                 bail!(self.span(), "loop seems to be infinite");
             }
 
+            vm.iterate(self.span())?;
+
             let value = body.eval(vm)?;
             output = ops::join(output, value).at(body.span())?;
 
             match vm.flow {
                 Some(FlowEvent::Break(_)) => {
                     vm.flow = None;
+                    broken = true;
                     break;
                 }
                 Some(FlowEvent::Continue(_)) => vm.flow = None,
@@ -1588,6 +2428,14 @@ impl Eval for ast::WhileLoop {
 
             i += 1;
         }
+        vm.loops -= 1;
+
+        if let Some(else_body) = self.else_body() {
+            if !broken && vm.flow.is_none() {
+                let value = else_body.eval(vm)?;
+                output = ops::join(output, value).at(else_body.span())?;
+            }
+        }
 
         if flow.is_some() {
             vm.flow = flow;
@@ -1626,13 +2474,16 @@ impl Eval for ast::ForLoop {
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let flow = vm.flow.take();
         let mut output = Value::None;
+        let mut broken = false;
 
         macro_rules! iter {
             (for $pat:ident in $iter:expr) => {{
                 vm.scopes.enter();
+                vm.loops += 1;
 
                 #[allow(unused_parens)]
                 for value in $iter {
+                    vm.iterate(self.span())?;
                     define_pattern(vm, &$pat, value.into_value())?;
 
                     let body = self.body();
@@ -1642,6 +2493,7 @@ impl Eval for ast::ForLoop {
                     match vm.flow {
                         Some(FlowEvent::Break(_)) => {
                             vm.flow = None;
+                            broken = true;
                             break;
                         }
                         Some(FlowEvent::Continue(_)) => vm.flow = None,
@@ -1650,17 +2502,32 @@ impl Eval for ast::ForLoop {
                     }
                 }
 
+                vm.loops -= 1;
                 vm.scopes.exit();
             }};
         }
 
-        let iter = self.iter().eval(vm)?;
+        let (iter, reversed) = eval_iterable(vm, self.iter())?;
         let pattern = self.pattern();
 
         match (&pattern, iter.clone()) {
-            (ast::Pattern::Normal(_), Value::Str(string)) => {
+            (ast::Pattern::Destructuring(_), Value::Str(string)) => {
+                // Iterate over pairs of index and grapheme of string.
+                let graphemes = string.as_str().graphemes(true);
+                let pair = |(i, c): (usize, &str)| array![i, c];
+                if reversed {
+                    iter!(for pattern in graphemes.rev().enumerate().map(pair));
+                } else {
+                    iter!(for pattern in graphemes.enumerate().map(pair));
+                }
+            }
+            (_, Value::Str(string)) => {
                 // Iterate over graphemes of string.
-                iter!(for pattern in string.as_str().graphemes(true));
+                if reversed {
+                    iter!(for pattern in string.as_str().graphemes(true).rev());
+                } else {
+                    iter!(for pattern in string.as_str().graphemes(true));
+                }
             }
             (_, Value::Dict(dict)) => {
                 // Iterate over pairs of dict.
@@ -1668,7 +2535,11 @@ impl Eval for ast::ForLoop {
             }
             (_, Value::Array(array)) => {
                 // Iterate over values of array.
-                iter!(for pattern in array);
+                if reversed {
+                    iter!(for pattern in array.iter().rev().cloned());
+                } else {
+                    iter!(for pattern in array);
+                }
             }
             (ast::Pattern::Normal(_), _) => {
                 bail!(self.iter().span(), "cannot loop over {}", iter.type_name());
@@ -1678,6 +2549,13 @@ impl Eval for ast::ForLoop {
             }
         }
 
+        if let Some(else_body) = self.else_body() {
+            if !broken && vm.flow.is_none() {
+                let value = else_body.eval(vm)?;
+                output = ops::join(output, value).at(else_body.span())?;
+            }
+        }
+
         if flow.is_some() {
             vm.flow = flow;
         }
@@ -1686,32 +2564,74 @@ impl Eval for ast::ForLoop {
     }
 }
 
+/// Evaluate the iterable of a for loop.
+///
+/// If the iterable is a call to `rev()` on an array or string, the target is
+/// returned as is together with `true` so that the loop can iterate over it
+/// backwards without materializing a reversed copy.
+fn eval_iterable(vm: &mut Vm, expr: ast::Expr) -> SourceResult<(Value, bool)> {
+    let ast::Expr::FuncCall(call) = &expr else {
+        return Ok((expr.eval(vm)?, false));
+    };
+
+    let mut reversed = false;
+    let value = eval_call(vm, call, Some(&mut reversed))?;
+    Ok((value.spanned(expr.span()), reversed))
+}
+
 /// Applies imports from `import` to the current scope.
 fn apply_imports<V: IntoValue>(
-    imports: Option<ast::Imports>,
+    import: &ast::ModuleImport,
     vm: &mut Vm,
     source_value: V,
     name: impl Fn(&V) -> EcoString,
     scope: impl Fn(&V) -> &Scope,
 ) -> SourceResult<()> {
-    match imports {
+    match import.imports() {
         None => {
             vm.scopes.top.define(name(&source_value), source_value);
         }
         Some(ast::Imports::Wildcard) => {
-            for (var, value) in scope(&source_value).iter() {
-                vm.scopes.top.define(var.clone(), value.clone());
+            let scope = scope(&source_value);
+            let exclusions = import.exclusions();
+            let errors: Vec<_> = exclusions
+                .iter()
+                .filter(|ident| scope.get(ident).is_none())
+                .map(|ident| error!(ident.span(), "unresolved exclusion"))
+                .collect();
+            if !errors.is_empty() {
+                return Err(Box::new(errors));
+            }
+            for (var, value) in scope.iter() {
+                if !exclusions.iter().any(|ident| ident.as_str() == var.as_str()) {
+                    vm.scopes.top.define(var.clone(), value.clone());
+                }
             }
         }
-        Some(ast::Imports::Items(idents)) => {
+        Some(ast::Imports::Items(items)) => {
             let mut errors = vec![];
             let scope = scope(&source_value);
-            for ident in idents {
-                if let Some(value) = scope.get(&ident) {
-                    vm.define(ident, value.clone());
-                } else {
+            for item in items {
+                let ident = item.original_name();
+                let Some(value) = scope.get(&ident) else {
                     errors.push(error!(ident.span(), "unresolved import"));
+                    continue;
+                };
+
+                let mut value = value.clone();
+                if let ast::ImportItem::Path(path) = &item {
+                    for segment in path.segments().skip(1) {
+                        match value.field(&segment).at(segment.span()) {
+                            Ok(field) => value = field,
+                            Err(err) => {
+                                errors.extend(*err);
+                                break;
+                            }
+                        }
+                    }
                 }
+
+                vm.define(item.bound_name(), value);
             }
             if !errors.is_empty() {
                 return Err(Box::new(errors));
@@ -1734,16 +2654,31 @@ impl Eval for ast::ModuleImport {
                 bail!(span, "cannot import from user-defined functions");
             }
             apply_imports(
-                self.imports(),
+                self,
                 vm,
                 func,
                 |func| func.info().unwrap().name.into(),
                 |func| &func.info().unwrap().scope,
             )?;
+        } else if let Value::Dict(dict) = source {
+            if self.imports().is_none() {
+                bail!(span, "cannot import a dictionary without specifying items");
+            }
+            let mut scope = Scope::new();
+            for (key, value) in dict {
+                scope.define(key, value);
+            }
+            apply_imports(
+                self,
+                vm,
+                Module::new("dictionary").with_scope(scope),
+                |module| module.name().clone(),
+                |module| module.scope(),
+            )?;
         } else {
             let module = import(vm, source, span, true)?;
             apply_imports(
-                self.imports(),
+                self,
                 vm,
                 module,
                 |module| module.name().clone(),
@@ -1779,13 +2714,21 @@ fn import(
         Value::Module(module) => return Ok(module),
         v => {
             if accept_functions {
-                bail!(span, "expected path, module or function, found {}", v.type_name())
+                bail!(
+                    span,
+                    "expected path, module, function or dictionary, found {}",
+                    v.type_name()
+                )
             } else {
                 bail!(span, "expected path or module, found {}", v.type_name())
             }
         }
     };
 
+    if !vm.limits.files {
+        bail!(span, "cannot access files in this context");
+    }
+
     // Handle package and file imports.
     let path = path.as_str();
     if path.starts_with('@') {
@@ -1825,7 +2768,10 @@ fn import_file(vm: &mut Vm, path: &str, span: Span) -> SourceResult<Module> {
         bail!(span, "cyclic import");
     }
 
-    // Evaluate the file.
+    // Evaluate the file. Imports are evaluated one after another in source
+    // order: Neither the world nor the tracer can be shared across threads,
+    // and the order determines which import's error is reported first.
+    // Repeated imports of the same file are cheap since `eval` is memoized.
     let point = || Tracepoint::Import;
     eval(world, vm.route, TrackedMut::reborrow_mut(&mut vm.vt.tracer), &source)
         .trace(world, point, span)
@@ -1882,6 +2828,9 @@ impl Eval for ast::LoopBreak {
 
     #[tracing::instrument(name = "LoopBreak::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        if vm.loops == 0 {
+            bail!(outside_of_loop(vm, self.span(), "break"));
+        }
         if vm.flow.is_none() {
             vm.flow = Some(FlowEvent::Break(self.span()));
         }
@@ -1894,6 +2843,9 @@ impl Eval for ast::LoopContinue {
 
     #[tracing::instrument(name = "LoopContinue::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        if vm.loops == 0 {
+            bail!(outside_of_loop(vm, self.span(), "continue"));
+        }
         if vm.flow.is_none() {
             vm.flow = Some(FlowEvent::Continue(self.span()));
         }
@@ -1901,14 +2853,34 @@ impl Eval for ast::LoopContinue {
     }
 }
 
+/// The error for a `break` or `continue` that is not inside of a loop.
+fn outside_of_loop(vm: &Vm, span: Span, keyword: &str) -> SourceDiagnostic {
+    let error = error!(span, "cannot {keyword} outside of loop");
+    if vm.depth > 0 {
+        error.with_hint(eco_format!(
+            "`{keyword}` cannot leave a function to reach a loop it was called from"
+        ))
+    } else {
+        error
+    }
+}
+
 impl Eval for ast::FuncReturn {
     type Output = Value;
 
     #[tracing::instrument(name = "FuncReturn::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        // Only closure bodies are evaluated at a nonzero call depth.
+        if vm.depth == 0 {
+            bail!(error!(self.span(), "cannot return outside of function")
+                .with_hint("`return` can only be used in the body of a function".into()));
+        }
+
         let value = self.body().map(|body| body.eval(vm)).transpose()?;
-        if vm.flow.is_none() {
-            vm.flow = Some(FlowEvent::Return(self.span(), value));
+        match &mut vm.flow {
+            None => vm.flow = Some(FlowEvent::Return(self.span(), value)),
+            Some(FlowEvent::TailCall(_, _, explicit @ None)) => *explicit = value,
+            Some(_) => {}
         }
         Ok(Value::None)
     }
@@ -1954,16 +2926,20 @@ impl Access for ast::Parenthesized {
 
 impl Access for ast::FieldAccess {
     fn access<'a>(&self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
-        access_dict(vm, self)?.at_mut(&self.field().take()).at(self.span())
+        access_field(self.target().access(vm)?, self)
     }
 }
 
-fn access_dict<'a>(
-    vm: &'a mut Vm,
+/// Access a field on an already accessed target value.
+fn access_field<'a>(
+    target: &'a mut Value,
     access: &ast::FieldAccess,
-) -> SourceResult<&'a mut Dict> {
-    match access.target().access(vm)? {
-        Value::Dict(dict) => Ok(dict),
+) -> SourceResult<&'a mut Value> {
+    let field = access.field().take();
+    match target {
+        Value::Dict(dict) => dict.at_mut(&field).at(access.span()),
+        Value::Array(array) if field == "first" => array.first_mut().at(access.span()),
+        Value::Array(array) if field == "last" => array.last_mut().at(access.span()),
         value => {
             let type_name = value.type_name();
             let span = access.target().span();
@@ -76,6 +76,12 @@ impl Module {
         })
     }
 
+    /// Iterate over the names of the module's definitions and whether each of
+    /// them is a function.
+    pub fn bindings(&self) -> impl Iterator<Item = (&EcoString, bool)> {
+        self.scope().bindings()
+    }
+
     /// Extract the module's content.
     pub fn content(self) -> Content {
         match Arc::try_unwrap(self.inner) {
@@ -5,7 +5,7 @@ use std::fmt::Debug;
 
 use ecow::eco_format;
 
-use super::{format_str, Regex, Value};
+use super::{format_str, DictKey, Regex, Value};
 use crate::diag::{bail, StrResult};
 use crate::geom::{Axes, Axis, GenAlign, Length, Numeric, PartialStroke, Rel, Smart};
 use Value::*;
@@ -33,6 +33,11 @@ pub fn join(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Symbol(a), Content(b)) => Content(item!(text)(a.get().into()) + b),
         (Array(a), Array(b)) => Array(a + b),
         (Dict(a), Dict(b)) => Dict(a + b),
+
+        (Styles(a), Styles(mut b)) => {
+            b.apply(a);
+            Styles(b)
+        }
         (a, b) => mismatch!("cannot join {} with {}", a, b),
     })
 }
@@ -105,6 +110,11 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Array(a), Array(b)) => Array(a + b),
         (Dict(a), Dict(b)) => Dict(a + b),
 
+        (Styles(a), Styles(mut b)) => {
+            b.apply(a);
+            Styles(b)
+        }
+
         (Color(color), Length(thickness)) | (Length(thickness), Color(color)) => {
             Value::dynamic(PartialStroke {
                 paint: Smart::Custom(color.into()),
@@ -205,8 +215,8 @@ pub fn mul(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Float(a), Fraction(b)) => Fraction(a * b),
         (Ratio(a), Fraction(b)) => Fraction(a.get() * b),
 
-        (Str(a), Int(b)) => Str(a.repeat(b)?),
-        (Int(a), Str(b)) => Str(b.repeat(a)?),
+        (Str(a), Int(b)) => Str(a.repeat(b, None)?),
+        (Int(a), Str(b)) => Str(b.repeat(a, None)?),
         (Array(a), Int(b)) => Array(a.repeat(b)?),
         (Int(a), Array(b)) => Array(b.repeat(a)?),
         (Content(a), b @ Int(_)) => Content(a.repeat(b.cast()?)),
@@ -424,6 +434,7 @@ pub fn contains(lhs: &Value, rhs: &Value) -> Option<bool> {
         (Str(a), Str(b)) => Some(b.as_str().contains(a.as_str())),
         (Dyn(a), Str(b)) => a.downcast::<Regex>().map(|regex| regex.is_match(b)),
         (Str(a), Dict(b)) => Some(b.contains(a)),
+        (&Int(a), Dict(b)) => Some(b.contains(&DictKey::from(a).0)),
         (a, Array(b)) => Some(b.contains(a)),
         _ => Option::None,
     }
@@ -0,0 +1,59 @@
+/// A small, deterministic pseudo-random number generator.
+///
+/// Uses the SplitMix64 algorithm, which is fast, has a tiny state and
+/// produces the same sequence on every platform for a given seed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a generator from an explicit seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Produce the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Produce a float in the range `0.0..1.0`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Produce an integer in the range `0..bound`.
+    ///
+    /// Returns zero if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+            assert!(rng.below(6) < 6);
+        }
+        assert_eq!(rng.below(0), 0);
+    }
+}
@@ -130,6 +130,12 @@ impl Scope {
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Value)> {
         self.0.iter().map(|(k, v)| (k, v.read()))
     }
+
+    /// Iterate over the names of all definitions and whether each of them is
+    /// a function.
+    pub fn bindings(&self) -> impl Iterator<Item = (&EcoString, bool)> {
+        self.iter().map(|(k, v)| (k, matches!(v, Value::Func(_))))
+    }
 }
 
 impl Debug for Scope {
@@ -5,6 +5,7 @@ use std::ops::{Add, AddAssign, Deref, Range};
 
 use ecow::EcoString;
 use serde::Serialize;
+use typst_macros::Cast;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::{cast, dict, Args, Array, Dict, Func, IntoValue, Value, Vm};
@@ -45,6 +46,15 @@ impl Str {
         self.0.len()
     }
 
+    /// The length of the string in the given unit.
+    pub fn len_in(&self, unit: StrUnit) -> usize {
+        match unit {
+            StrUnit::Bytes => self.0.len(),
+            StrUnit::Codepoints => self.0.chars().count(),
+            StrUnit::Graphemes => self.0.graphemes(true).count(),
+        }
+    }
+
     /// A string slice containing the entire string.
     pub fn as_str(&self) -> &str {
         self
@@ -89,9 +99,142 @@ impl Str {
         self.as_str().graphemes(true).map(|s| Value::Str(s.into())).collect()
     }
 
-    /// The codepoints the string consists of.
+    /// The words of the string, split at Unicode word boundaries.
+    ///
+    /// Whitespace-only segments are only kept if `whitespace` is true.
+    pub fn words(&self, whitespace: bool) -> Array {
+        segments(self.as_str().split_word_bounds(), whitespace)
+    }
+
+    /// The sentences of the string, split at Unicode sentence boundaries.
+    ///
+    /// Whitespace-only segments are only kept if `whitespace` is true.
+    pub fn sentences(&self, whitespace: bool) -> Array {
+        segments(self.as_str().split_sentence_bounds(), whitespace)
+    }
+
+    /// The numeric codepoints the string consists of.
     pub fn codepoints(&self) -> Array {
-        self.chars().map(|c| Value::Str(c.into())).collect()
+        self.chars().map(|c| Value::Int(c as i64)).collect()
+    }
+
+    /// The string with its grapheme clusters in reverse order.
+    pub fn rev(&self) -> Self {
+        self.as_str().graphemes(true).rev().collect::<String>().into()
+    }
+
+    /// The string converted to lowercase.
+    pub fn lower(&self) -> Self {
+        self.as_str().to_lowercase().into()
+    }
+
+    /// The string converted to uppercase.
+    pub fn upper(&self) -> Self {
+        self.as_str().to_uppercase().into()
+    }
+
+    /// The string with the first letter of each word converted to uppercase.
+    pub fn title(&self) -> Self {
+        let mut s = EcoString::with_capacity(self.len());
+        for word in self.as_str().split_word_bounds() {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) if first.is_alphabetic() => {
+                    s.extend(first.to_uppercase());
+                    s.push_str(chars.as_str());
+                }
+                _ => s.push_str(word),
+            }
+        }
+        s.into()
+    }
+
+    /// Substitute the placeholders in this string with the given arguments.
+    ///
+    /// A placeholder refers to the next positional argument (`{}`), to a
+    /// positional argument by index (`{0}`), or to a named argument
+    /// (`{name}`). After a colon, it may specify a minimum width, with a
+    /// leading zero requesting zero padding for numbers (`{:05}`). Doubled
+    /// braces produce literal braces.
+    pub fn format(&self, pos: &Array, named: &Dict) -> StrResult<Self> {
+        let mut out = EcoString::new();
+        let mut next = 0;
+        let mut rest = self.as_str();
+
+        while let Some(i) = rest.find(['{', '}']) {
+            out.push_str(&rest[..i]);
+            let brace = rest[i..].starts_with('{');
+            rest = &rest[i + 1..];
+
+            // Escaped braces.
+            if rest.starts_with(if brace { '{' } else { '}' }) {
+                out.push(if brace { '{' } else { '}' });
+                rest = &rest[1..];
+                continue;
+            }
+
+            if !brace {
+                bail!("unmatched closing brace in format string");
+            }
+
+            let Some(end) = rest.find('}').filter(|&end| !rest[..end].contains('{'))
+            else {
+                bail!("unclosed placeholder in format string");
+            };
+
+            let placeholder = &rest[..end];
+            rest = &rest[end + 1..];
+
+            let (key, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+            let value = if key.is_empty() {
+                next += 1;
+                pos.as_slice().get(next - 1).ok_or_else(|| {
+                    eco_format!("format string has more placeholders than arguments")
+                })?
+            } else if let Ok(index) = key.parse::<usize>() {
+                pos.as_slice().get(index).ok_or_else(|| {
+                    eco_format!("format string refers to missing argument {index}")
+                })?
+            } else {
+                named
+                    .iter()
+                    .find(|(name, _)| name.as_str() == key)
+                    .map(|(_, v)| v)
+                    .ok_or_else(|| {
+                        eco_format!("format string refers to missing argument {key:?}")
+                    })?
+            };
+
+            format_placeholder(&mut out, value, spec)?;
+        }
+
+        out.push_str(rest);
+        Ok(out.into())
+    }
+
+    /// Parse the string as an integer in the given base, optionally ignoring
+    /// surrounding whitespace.
+    pub fn to_int(&self, base: i64, trim: bool) -> StrResult<i64> {
+        if !(2..=36).contains(&base) {
+            bail!("base must be between 2 and 36");
+        }
+
+        let text = if trim { self.as_str().trim() } else { self.as_str() };
+        i64::from_str_radix(text, base as u32).map_err(|_| {
+            if base == 10 {
+                eco_format!("invalid integer: {}", self.as_str())
+            } else {
+                eco_format!("invalid integer in base {base}: {}", self.as_str())
+            }
+        })
+    }
+
+    /// Parse the string as a float, optionally ignoring surrounding
+    /// whitespace.
+    pub fn to_float(&self, trim: bool) -> StrResult<f64> {
+        let text = if trim { self.as_str().trim() } else { self.as_str() };
+        text.parse()
+            .map_err(|_| eco_format!("invalid float: {}", self.as_str()))
     }
 
     /// Whether the given pattern exists in this string.
@@ -115,7 +258,18 @@ impl Str {
         match pattern {
             StrPattern::Str(pat) => self.0.ends_with(pat.as_str()),
             StrPattern::Regex(re) => {
-                re.find_iter(self).last().map_or(false, |m| m.end() == self.0.len())
+                let mut start = 0;
+                while let Some(m) = re.find_at(self, start) {
+                    if m.end() == self.0.len() {
+                        return true;
+                    }
+
+                    // A match overlapping this one might still end at the
+                    // end, so restart at the next code point.
+                    let Some(c) = self[m.start()..].chars().next() else { break };
+                    start = m.start() + c.len_utf8();
+                }
+                false
             }
         }
     }
@@ -136,6 +290,26 @@ impl Str {
         }
     }
 
+    /// The text of the pattern's last match in this string.
+    pub fn rfind(&self, pattern: StrPattern) -> Option<Self> {
+        match pattern {
+            StrPattern::Str(pat) => self.0.contains(pat.as_str()).then_some(pat),
+            StrPattern::Regex(re) => {
+                last_regex_match(self, &re).map(|m| m.as_str().into())
+            }
+        }
+    }
+
+    /// The position of the pattern's last match in this string.
+    pub fn rposition(&self, pattern: StrPattern) -> Option<i64> {
+        match pattern {
+            StrPattern::Str(pat) => self.0.rfind(pat.as_str()).map(|i| i as i64),
+            StrPattern::Regex(re) => {
+                last_regex_match(self, &re).map(|m| m.start() as i64)
+            }
+        }
+    }
+
     /// The start and, text and capture groups (if any) of the first match of
     /// the pattern in this string.
     pub fn match_(&self, pattern: StrPattern) -> Option<Dict> {
@@ -313,14 +487,31 @@ impl Str {
         Ok(output.into())
     }
 
-    /// Repeat the string a number of times.
-    pub fn repeat(&self, n: i64) -> StrResult<Self> {
+    /// Repeat the string a number of times, optionally with a separator
+    /// between the repetitions.
+    pub fn repeat(&self, n: i64, separator: Option<&str>) -> StrResult<Self> {
+        let sep = separator.unwrap_or_default();
         let n = usize::try_from(n)
             .ok()
-            .and_then(|n| self.0.len().checked_mul(n).map(|_| n))
+            .and_then(|n| {
+                let len = self.0.len().checked_mul(n)?;
+                sep.len().checked_mul(n.saturating_sub(1))?.checked_add(len)?;
+                Some(n)
+            })
             .ok_or_else(|| format!("cannot repeat this string {} times", n))?;
 
-        Ok(Self(self.0.repeat(n)))
+        if sep.is_empty() {
+            return Ok(Self(self.0.repeat(n)));
+        }
+
+        let mut s = EcoString::new();
+        for i in 0..n {
+            if i > 0 {
+                s.push_str(sep);
+            }
+            s.push_str(&self.0);
+        }
+        Ok(Self(s))
     }
 
     /// Resolve an index or throw an out of bounds error.
@@ -348,6 +539,14 @@ impl Str {
     }
 }
 
+/// Collect text segments into an array, optionally dropping those that
+/// consist only of whitespace.
+fn segments<'a>(iter: impl Iterator<Item = &'a str>, whitespace: bool) -> Array {
+    iter.filter(|s| whitespace || !s.chars().all(char::is_whitespace))
+        .map(|s| Value::Str(s.into()))
+        .collect()
+}
+
 /// The out of bounds access error message.
 #[cold]
 fn out_of_bounds(index: i64, len: usize) -> EcoString {
@@ -372,6 +571,53 @@ fn string_is_empty() -> EcoString {
     "string is empty".into()
 }
 
+/// Write a value into a format string according to a format specification.
+fn format_placeholder(out: &mut EcoString, value: &Value, spec: &str) -> StrResult<()> {
+    let (zero, width) = match spec.strip_prefix('0') {
+        Some(width) if !width.is_empty() => (true, width),
+        _ => (false, spec),
+    };
+
+    let width = if width.is_empty() {
+        0
+    } else {
+        width
+            .parse::<usize>()
+            .map_err(|_| eco_format!("invalid format specification {spec:?}"))?
+    };
+
+    let numeric = matches!(value, Value::Int(_) | Value::Float(_));
+    if zero && !numeric {
+        bail!("zero padding is only supported for numbers");
+    }
+
+    let text: EcoString = match value {
+        Value::Int(n) => eco_format!("{n}"),
+        Value::Float(n) => eco_format!("{n}"),
+        Value::Str(s) => s.0.clone(),
+        Value::Label(label) => label.0.clone(),
+        v => v.repr().0,
+    };
+
+    let padding = width.saturating_sub(text.chars().count());
+    if zero {
+        let digits = text.strip_prefix('-').unwrap_or(&text);
+        if digits.len() < text.len() {
+            out.push('-');
+        }
+        out.extend(std::iter::repeat('0').take(padding));
+        out.push_str(digits);
+    } else if numeric {
+        out.extend(std::iter::repeat(' ').take(padding));
+        out.push_str(&text);
+    } else {
+        out.push_str(&text);
+        out.extend(std::iter::repeat(' ').take(padding));
+    }
+
+    Ok(())
+}
+
 /// Convert an item of std's `match_indices` to a dictionary.
 fn match_to_dict((start, text): (usize, &str)) -> Dict {
     dict! {
@@ -396,6 +642,12 @@ fn captures_to_dict(cap: regex::Captures) -> Dict {
     }
 }
 
+/// The last of the non-overlapping matches of the regex in the string, as
+/// found by `matches`.
+fn last_regex_match<'a>(string: &'a str, re: &Regex) -> Option<regex::Match<'a>> {
+    re.find_iter(string).last()
+}
+
 impl Deref for Str {
     type Target = str;
 
@@ -604,6 +856,18 @@ cast! {
     },
 }
 
+/// A unit in which the length of a string can be measured.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum StrUnit {
+    /// UTF-8 encoded bytes, as used by indices.
+    Bytes,
+    /// Unicode codepoints.
+    Codepoints,
+    /// Grapheme clusters, as produced by iteration.
+    #[default]
+    Graphemes,
+}
+
 /// A replacement for a matched [`Str`]
 pub enum Replacement {
     /// A string a match is replaced with.
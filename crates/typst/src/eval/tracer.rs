@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use ecow::{eco_vec, EcoVec};
 
 use super::Value;
-use crate::diag::SourceDiagnostic;
+use crate::diag::{sort_diagnostics, SourceDiagnostic};
 use crate::syntax::{FileId, Span};
 use crate::util::hash128;
 
@@ -35,9 +35,11 @@ impl Tracer {
         self.values
     }
 
-    /// Get the stored warnings.
+    /// Get the stored warnings, sorted by their position in the source.
     pub fn warnings(self) -> EcoVec<SourceDiagnostic> {
-        self.warnings
+        let mut warnings = self.warnings;
+        sort_diagnostics(warnings.make_mut());
+        warnings
     }
 }
 
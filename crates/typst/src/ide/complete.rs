@@ -475,7 +475,7 @@ fn complete_imports(ctx: &mut CompletionContext) -> bool {
 /// Add completions for all exports of a module.
 fn import_item_completions(
     ctx: &mut CompletionContext,
-    existing: &[ast::Ident],
+    existing: &[ast::ImportItem],
     value: &Value,
 ) {
     let module = match value {
@@ -492,7 +492,7 @@ fn import_item_completions(
     }
 
     for (name, value) in module.scope().iter() {
-        if existing.iter().all(|ident| ident.as_str() != name) {
+        if existing.iter().all(|item| item.original_name().as_str() != name) {
             ctx.value_completion(Some(name.clone()), value, false, None);
         }
     }
@@ -1024,7 +1024,8 @@ impl<'a> CompletionContext<'a> {
 
     /// Add completions for raw block tags.
     fn raw_completions(&mut self) {
-        for (name, mut tags) in (self.library.items.raw_languages)() {
+        for (name, tags) in (self.library.items.raw_languages)() {
+            let mut tags = tags.clone();
             let lower = name.to_lowercase();
             if !tags.contains(&lower.as_str()) {
                 tags.push(lower.as_str());
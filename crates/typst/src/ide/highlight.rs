@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::syntax::{ast, LinkedNode, SyntaxKind, SyntaxNode};
 
 /// A syntax highlighting tag.
@@ -130,10 +132,13 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Label => Some(Tag::Label),
         SyntaxKind::Ref => Some(Tag::Ref),
         SyntaxKind::RefMarker => None,
+        SyntaxKind::FootnoteRef => Some(Tag::Ref),
+        SyntaxKind::FootnoteDef => None,
         SyntaxKind::Heading => Some(Tag::Heading),
         SyntaxKind::HeadingMarker => None,
         SyntaxKind::ListItem => None,
         SyntaxKind::ListMarker => Some(Tag::ListMarker),
+        SyntaxKind::Checkbox => Some(Tag::ListMarker),
         SyntaxKind::EnumItem => None,
         SyntaxKind::EnumMarker => Some(Tag::ListMarker),
         SyntaxKind::TermItem => None,
@@ -177,6 +182,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Hat => Some(Tag::MathOperator),
         SyntaxKind::Prime => Some(Tag::MathOperator),
         SyntaxKind::Dot => Some(Tag::Punctuation),
+        SyntaxKind::QuestionDot => Some(Tag::Punctuation),
         SyntaxKind::Eq => match node.parent_kind() {
             Some(SyntaxKind::Heading) => None,
             _ => Some(Tag::Operator),
@@ -191,6 +197,8 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::HyphEq => Some(Tag::Operator),
         SyntaxKind::StarEq => Some(Tag::Operator),
         SyntaxKind::SlashEq => Some(Tag::Operator),
+        SyntaxKind::AndEq => Some(Tag::Keyword),
+        SyntaxKind::OrEq => Some(Tag::Keyword),
         SyntaxKind::Dots => Some(Tag::Operator),
         SyntaxKind::Arrow => Some(Tag::Operator),
         SyntaxKind::Root => Some(Tag::MathOperator),
@@ -214,6 +222,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Import => Some(Tag::Keyword),
         SyntaxKind::Include => Some(Tag::Keyword),
         SyntaxKind::As => Some(Tag::Keyword),
+        SyntaxKind::Except => Some(Tag::Keyword),
 
         SyntaxKind::Code => None,
         SyntaxKind::Ident => highlight_ident(node),
@@ -237,7 +246,9 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Spread => None,
         SyntaxKind::Closure => None,
         SyntaxKind::Params => None,
+        SyntaxKind::TypedParam => None,
         SyntaxKind::LetBinding => None,
+        SyntaxKind::TypeAnnotation => None,
         SyntaxKind::SetRule => None,
         SyntaxKind::ShowRule => None,
         SyntaxKind::Conditional => None,
@@ -245,6 +256,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::ForLoop => None,
         SyntaxKind::ModuleImport => None,
         SyntaxKind::ImportItems => None,
+        SyntaxKind::ImportPath => None,
         SyntaxKind::ModuleInclude => None,
         SyntaxKind::LoopBreak => None,
         SyntaxKind::LoopContinue => None,
@@ -303,7 +315,7 @@ fn highlight_ident(node: &LinkedNode) -> Option<Tag> {
 
     // Are we behind a dot, that is behind another identifier?
     let prev = node.prev_leaf()?;
-    if prev.kind() == SyntaxKind::Dot {
+    if matches!(prev.kind(), SyntaxKind::Dot | SyntaxKind::QuestionDot) {
         let prev_prev = prev.prev_leaf()?;
         if is_ident(&prev_prev) {
             return highlight_ident(&prev_prev);
@@ -328,6 +340,29 @@ fn is_ident(node: &LinkedNode) -> bool {
     matches!(node.kind(), SyntaxKind::Ident | SyntaxKind::MathIdent)
 }
 
+/// Determine the highlight tags of all nodes in a syntax tree together with
+/// their byte ranges.
+///
+/// The ranges are in pre-order, so the range of a node comes before the ranges
+/// of its descendants, which may be tagged differently. Nodes that should not
+/// be highlighted are skipped.
+pub fn highlight_ranges(root: &SyntaxNode) -> Vec<(Range<usize>, Tag)> {
+    let mut tags = vec![];
+    highlight_ranges_impl(&mut tags, &LinkedNode::new(root));
+    tags
+}
+
+/// Collect the highlight tags of one node and its descendants.
+fn highlight_ranges_impl(tags: &mut Vec<(Range<usize>, Tag)>, node: &LinkedNode) {
+    if let Some(tag) = highlight(node) {
+        tags.push((node.range(), tag));
+    }
+
+    for child in node.children() {
+        highlight_ranges_impl(tags, &child);
+    }
+}
+
 /// Highlight a node to an HTML `code` element.
 ///
 /// This uses these [CSS classes for categories](Tag::css_class).
@@ -376,8 +411,6 @@ fn highlight_html_impl(html: &mut String, node: &LinkedNode) {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::Range;
-
     use super::*;
     use crate::syntax::Source;
 
@@ -387,20 +420,8 @@ mod tests {
 
         #[track_caller]
         fn test(text: &str, goal: &[(Range<usize>, Tag)]) {
-            let mut vec = vec![];
             let source = Source::detached(text);
-            highlight_tree(&mut vec, &LinkedNode::new(source.root()));
-            assert_eq!(vec, goal);
-        }
-
-        fn highlight_tree(tags: &mut Vec<(Range<usize>, Tag)>, node: &LinkedNode) {
-            if let Some(tag) = highlight(node) {
-                tags.push((node.range(), tag));
-            }
-
-            for child in node.children() {
-                highlight_tree(tags, &child);
-            }
+            assert_eq!(highlight_ranges(source.root()), goal);
         }
 
         test("= *AB*", &[(0..6, Heading), (2..6, Strong)]);
@@ -79,10 +79,17 @@ pub fn compile(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Document>
         route.track(),
         TrackedMut::reborrow_mut(&mut tracer),
         &world.main(),
-    )?;
+    );
 
     // Typeset it.
-    model::typeset(world, tracer, &module.content())
+    let result =
+        module.and_then(|module| model::typeset(world, tracer, &module.content()));
+
+    // Report errors in a deterministic order.
+    result.map_err(|mut errors| {
+        diag::sort_diagnostics(&mut errors);
+        errors
+    })
 }
 
 /// The environment in which typesetting occurs.
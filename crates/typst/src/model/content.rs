@@ -450,14 +450,15 @@ impl Debug for Content {
             return f.write_str("[ ]");
         }
 
-        let mut pieces: Vec<_> = self
-            .fields()
-            .map(|(name, value)| eco_format!("{name}: {value:?}"))
-            .collect();
-
-        if self.is::<StyledElem>() {
-            pieces.push(EcoString::from(".."));
-        }
+        let pieces: Vec<_> = if let Some(children) = self.to_sequence() {
+            children.map(|child| eco_format!("{child:?}")).collect()
+        } else if let Some((child, _)) = self.to_styled() {
+            vec![eco_format!("{child:?}"), EcoString::from("..")]
+        } else {
+            self.fields()
+                .map(|(name, value)| eco_format!("{name}: {value:?}"))
+                .collect()
+        };
 
         f.write_str(name)?;
         f.write_str(&pretty_array_like(&pieces, false))
@@ -470,12 +471,15 @@ impl Default for Content {
     }
 }
 
+/// Content compares structurally: Sequences compare child by child, styled
+/// content compares its child and its styles (see [`Styles::same`]), and all
+/// other elements compare their function and fields. Spans are ignored.
 impl PartialEq for Content {
     fn eq(&self, other: &Self) -> bool {
         if let (Some(left), Some(right)) = (self.to_sequence(), other.to_sequence()) {
             left.eq(right)
         } else if let (Some(left), Some(right)) = (self.to_styled(), other.to_styled()) {
-            left == right
+            left.0 == right.0 && left.1.same(right.1)
         } else {
             self.func == other.func && self.fields_ref().eq(other.fields_ref())
         }
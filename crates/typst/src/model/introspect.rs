@@ -308,6 +308,11 @@ impl Introspector {
         }
     }
 
+    /// Query for all elements that have a label.
+    pub fn query_labelled(&self) -> EcoVec<Prehashed<Content>> {
+        self.all().filter(|elem| elem.label().is_some()).cloned().collect()
+    }
+
     /// Query for a unique element with the label.
     pub fn query_label(&self, label: &Label) -> StrResult<Prehashed<Content>> {
         let mut found = None;
@@ -68,6 +68,25 @@ impl Styles {
         self
     }
 
+    /// Whether both lists contain the same properties and recipes, regardless
+    /// of the spans they originate from.
+    pub fn same(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(other.0.iter()).all(|(a, b)| match (&**a, &**b) {
+                (Style::Property(a), Style::Property(b)) => {
+                    a.element == b.element && a.name == b.name && a.value == b.value
+                }
+                (Style::Recipe(a), Style::Recipe(b)) => {
+                    a.selector == b.selector
+                        && match (&a.transform, &b.transform) {
+                            (Transform::Style(a), Transform::Style(b)) => a.same(b),
+                            (a, b) => a == b,
+                        }
+                }
+                _ => false,
+            })
+    }
+
     /// Returns `Some(_)` with an optional span if this list contains
     /// styles for the given element.
     pub fn interruption<T: Element>(&self) -> Option<Option<Span>> {
@@ -284,6 +303,7 @@ cast! {
     Transform,
     content: Content => Self::Content(content),
     func: Func => Self::Func(func),
+    styles: Styles => Self::Style(styles),
 }
 
 /// A chain of styles, similar to a linked list.
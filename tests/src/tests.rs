@@ -20,12 +20,15 @@ use tiny_skia as sk;
 use unscanny::Scanner;
 use walkdir::WalkDir;
 
-use typst::diag::{bail, FileError, FileResult, Severity, StrResult};
+use typst::diag::{bail, FileError, FileResult, Severity, SourceResult, StrResult};
 use typst::doc::{Document, Frame, FrameItem, Meta};
-use typst::eval::{eco_format, func, Bytes, Datetime, Library, NoneValue, Tracer, Value};
+use typst::eval::{
+    eco_format, eval_sandboxed, func, Bytes, Datetime, EvalMode, Library, Limits,
+    NoneValue, Scope, Str, Tracer, Value, Vm,
+};
 use typst::font::{Font, FontBook};
 use typst::geom::{Abs, Color, RgbaColor, Smart};
-use typst::syntax::{FileId, Source, Span, SyntaxNode};
+use typst::syntax::{FileId, Source, Span, Spanned, SyntaxNode};
 use typst::util::PathExt;
 use typst::World;
 use typst_library::layout::{Margin, PageElem};
@@ -169,6 +172,28 @@ fn library() -> Library {
         NoneValue
     }
 
+    /// Display: Sandbox
+    /// Category: test
+    #[func]
+    fn sandbox(code: Spanned<Str>, budget: usize, vm: &mut Vm) -> SourceResult<Value> {
+        let world = vm.world();
+        let limits = Limits {
+            iterations: budget,
+            calls: budget,
+            ..Limits::default()
+        };
+        let Spanned { v: code, span } = code;
+        eval_sandboxed(
+            world,
+            &code,
+            span,
+            EvalMode::Code,
+            Scope::new(),
+            world.library(),
+            limits,
+        )
+    }
+
     let mut lib = typst_library::build();
 
     // Set page width to 120pt with 10pt margins, so that the inner page is
@@ -185,6 +210,7 @@ fn library() -> Library {
     // Hook up helpers into the global scope.
     lib.global.scope_mut().define("test", test_func());
     lib.global.scope_mut().define("print", print_func());
+    lib.global.scope_mut().define("sandbox", sandbox_func());
     lib.global
         .scope_mut()
         .define("conifer", RgbaColor::new(0x9f, 0xEB, 0x52, 0xFF));
@@ -101,6 +101,22 @@
 // Error: 2-11 array is empty
 #().last()
 
+---
+// Test the `len`, `first` and `last` fields.
+#let values = (1, 2, 3)
+#test(values.len, 3)
+#test(values.first, 1)
+#test(values.last, 3)
+#test(().len, 0)
+
+---
+// Error: 5-10 array is empty
+#().first
+
+---
+// Error: 7-13 array does not contain field "middle"
+#(1,).middle
+
 ---
 // Test the `push` and `pop` methods.
 #{
@@ -111,6 +127,25 @@
   test(tasks.at("b"), (4, 5, 6, 7))
 }
 
+---
+// Test the return values of `push` and `pop`.
+#{
+  let array = (1,)
+  test(array.push(2), none)
+  test(array.pop(), 2)
+  test(array.pop(default: none), 1)
+  test(array.pop(default: none), none)
+  test(array.pop(default: 0), 0)
+  test(array, ())
+}
+
+---
+// Error: 3:3-3:14 array is empty
+#{
+  let array = ()
+  array.pop()
+}
+
 ---
 // Test the `insert` and `remove` methods.
 #{
@@ -119,6 +154,32 @@
   test(array, range(6))
   array.remove(1)
   test(array, (0, 2, 3, 4, 5))
+  array.insert(5, 6)
+  test(array, (0, 2, 3, 4, 5, 6))
+  test(array.remove(-1), 6)
+  test(array.remove(-5), 0)
+  test(array, (2, 3, 4, 5))
+}
+
+---
+// Error: 3:3-3:23 array index out of bounds (index: 4, len: 3)
+#{
+  let array = (1, 2, 3)
+  array.insert(4, "x")
+}
+
+---
+// Error: 3:3-3:18 array index out of bounds (index: 3, len: 3)
+#{
+  let array = (1, 2, 3)
+  array.remove(3)
+}
+
+---
+// Error: 3:3-3:19 array index out of bounds (index: -4, len: 3)
+#{
+  let array = (1, 2, 3)
+  array.remove(-4)
 }
 
 ---
@@ -202,6 +263,11 @@
 #test((1,).join(), 1)
 #test(("a", "b", "c").join(), "abc")
 #test("(" + ("a", "b", "c").join(", ") + ")", "(a, b, c)")
+#test(("a", "b", "c").join(", ", last: " and "), "a, b and c")
+#test(("a", "b").join(", ", last: " and "), "a and b")
+#test(("a",).join(", ", last: " and "), "a")
+#test(().join(", ", last: " and "), none)
+#test(([a],).join([, ]), [a])
 
 ---
 // Error: 2-22 cannot join boolean with boolean
@@ -228,6 +294,36 @@
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x), (-10, -7, -5, 1, 2, 2, 3, 6, 8))
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x * x), (1, 2, 2, 3, -5, 6, -7, 8, -10))
 
+---
+// Test descending and multi-key sorting.
+#test((3, 1, 2).sorted(descending: true), (3, 2, 1))
+#test(("I", "the", "hi", "text").sorted(key: x => x.len(), descending: true), ("text", "the", "hi", "I"))
+#let people = (
+  (name: "Bo", age: 30),
+  (name: "Al", age: 25),
+  (name: "Cy", age: 30),
+  (name: "Al", age: 20),
+)
+#test(
+  people.sorted(key: (p => p.name, p => p.age)).map(p => p.age),
+  (20, 25, 30, 30),
+)
+#test(
+  people.sorted(key: (p => p.age, p => p.name), descending: true).map(p => p.name),
+  ("Cy", "Bo", "Al", "Al"),
+)
+#test(people.sorted(key: p => p.age).map(p => p.name), ("Al", "Al", "Bo", "Cy"))
+#test((2, none, 1).sorted(), (1, 2, none))
+#test((2, none, 1).sorted(descending: true), (2, 1, none))
+
+---
+// Error: 22-26 expected function, found integer
+#((1, 2).sorted(key: (1,)))
+
+---
+// Error: 3-20 cannot compare string and integer
+#(("a", 1).sorted())
+
 ---
 // Test the `zip` method.
 #test(().zip(()), ())
@@ -255,6 +351,10 @@
 #test((1, 2, 1).dedup(), (1, 2))
 #test(("Jane", "John", "Eric").dedup(), ("Jane", "John", "Eric"))
 #test(("Jane", "John", "Eric", "John").dedup(), ("Jane", "John", "Eric"))
+#test((1, "1", true, none, 1, none, "1").dedup(), (1, "1", true, none))
+#test((1, 1.0, 2.0, 2, 3).dedup(), (1, 2.0, 3))
+#test(((1, 2), (1, 2), (2, 1)).dedup(), ((1, 2), (2, 1)))
+#test((<a>, <b>, <a>).dedup(), (<a>, <b>))
 
 ---
 // Test the `dedup` with the `key` argument.
@@ -296,7 +396,7 @@
 #(,1)
 
 // Missing expression makes named pair incomplete, making this an empty array.
-// Error: 5 expected expression
+// Error: 5 expected expression, found closing paren
 #(a:)
 
 // Named pair after this is already identified as an array.
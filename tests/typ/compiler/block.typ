@@ -27,6 +27,8 @@
 #test({}, none)
 
 // Let evaluates to none.
+// Warning: 13-14 unused variable: v
+// Hint: 13-14 prefix the name with an underscore to silence this warning
 #test({ let v = 0 }, none)
 
 // Evaluates to single expression.
@@ -59,6 +61,8 @@
 
 ---
 // Block directly in markup also creates a scope.
+// Warning: 8-9 unused variable: x
+// Hint: 8-9 prefix the name with an underscore to silence this warning
 #{ let x = 1 }
 
 // Error: 7-8 unknown variable: x
@@ -116,21 +120,21 @@
 #1u
 
 // Should output `1`.
-// Error: 4 expected semicolon or line break
+// Error: 4 expected semicolon or line break, found integer
 #{1 2}
 
 // Should output `2`.
-// Error: 13 expected semicolon or line break
-// Error: 23 expected semicolon or line break
+// Error: 13 expected semicolon or line break, found keyword `let`
+// Error: 23 expected semicolon or line break, found identifier
 #{let x = -1 let y = 3 x + y}
 
 // Should output `3`.
 #{
-  // Error: 6 expected identifier
+  // Error: 6 expected identifier, found string
   // Error: 10 expected block
   for "v"
 
-  // Error: 8 expected keyword `in`
+  // Error: 8 expected keyword `in`, found keyword `let`
   // Error: 22 expected block
   for v let z = 1 + 2
 
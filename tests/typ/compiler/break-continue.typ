@@ -68,6 +68,7 @@
 // Test break outside of loop.
 #let f() = {
   // Error: 3-8 cannot break outside of loop
+  // Hint: 3-8 `break` cannot leave a function to reach a loop it was called from
   break
 }
 
@@ -145,3 +146,28 @@
     for _ in range(3) [B]
   )
 }
+
+---
+// Test that break and continue are checked where they appear.
+#let x = 0
+#{
+  x += 1
+  // Error: 3-11 cannot continue outside of loop
+  continue
+  x += 1
+}
+
+---
+// Test continue in a closure within a loop.
+#for i in range(3) {
+  // Error: 19-27 cannot continue outside of loop
+  // Hint: 19-27 `continue` cannot leave a function to reach a loop it was called from
+  (1, 2).map(x => continue)
+}
+
+---
+// Test that loops are left properly after an error in them.
+// Error: 22-23 unknown variable: y
+#for i in range(1) { y }
+// Error: 2-7 cannot break outside of loop
+#break
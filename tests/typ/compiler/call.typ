@@ -70,18 +70,18 @@
 #f[1](2)
 
 ---
-// Error: 7 expected expression
-// Error: 8 expected expression
+// Error: 7 expected expression, found colon
+// Error: 8 expected expression, found closing paren
 #func(:)
 
 // Error: 10-12 unexpected end of block comment
 #func(a:1*/)
 
-// Error: 8 expected comma
+// Error: 8 expected comma, found integer
 #func(1 2)
 
 // Error: 7-8 expected identifier, found integer
-// Error: 9 expected expression
+// Error: 9 expected expression, found closing paren
 #func(1:)
 
 // Error: 7-8 expected identifier, found integer
@@ -105,3 +105,32 @@
 // Error: 6-7 unclosed delimiter
 // Error: 1:7-2:1 unclosed string
 #func("]
+
+---
+// An unclosed argument list ends at the next line that isn't indented.
+// Error: 6-7 unclosed delimiter
+#func(1, 2
+Text after the group is *markup* again.
+
+// Error: 10-11 unclosed delimiter
+#let x = (a: 1
+#x
+
+---
+// Error: 11 expected comma, found integer
+#func(1, 2
+  3)
+
+---
+// Trailing content blocks are positional arguments in order.
+#let f(..args) = args
+#test(f[a][b][c].pos(), ([a], [b], [c]))
+#test(f(1)[a][b].pos(), (1, [a], [b]))
+#test(f(x: 1)[a][b].pos(), ([a], [b]))
+#test(f(x: 1)[a][b].named(), (x: 1))
+#test(f(1, x: 2, ..(3, 4))[a].pos(), (1, 3, 4, [a]))
+
+---
+// Trailing content blocks fill grid cells.
+#let cells = grid(columns: 2)[a][b][c].children
+#test(cells, ([a], [b], [c]))
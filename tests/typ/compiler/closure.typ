@@ -108,6 +108,8 @@
 #{
   // Error: 16-17 unknown variable: x
   let func() = x
+  // Warning: 7-8 unused variable: x
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
   let x = "hi"
   func()
 }
@@ -145,6 +147,33 @@
   test(greet("Typst", whatever: 10))
 }
 
+---
+// Spreading a dictionary into named parameters.
+#{
+  let f(a, size: 1pt, fill: none) = (a, size, fill)
+  let config = (size: 2pt, fill: red)
+  test(f(1, ..config), (1, 2pt, red))
+  test(f(1, ..(size: 3pt)), (1, 3pt, none))
+  test(f(..(:), 1), (1, 1pt, none))
+
+  // Later arguments take precedence over earlier ones.
+  test(f(1, size: 4pt, ..config), (1, 2pt, red))
+  test(f(1, ..config, size: 4pt), (1, 4pt, red))
+  test(f(1, ..config, ..(fill: blue)), (1, 2pt, blue))
+}
+
+---
+// Keys without a matching parameter are captured by the sink.
+#{
+  let f(size: 1pt, ..rest) = (size, rest.named())
+  test(f(..(size: 2pt, fill: red, stroke: none)), (2pt, (fill: red, stroke: none)))
+  test(f(..(fill: red)), (1pt, (fill: red)))
+}
+
+---
+// Error: 30-43 unexpected argument: fill
+#let f(size: 1pt) = size; #f(..(fill: red))
+
 ---
 // Parameter unpacking.
 #let f((a, b), ..c) = (a, b, c)
@@ -195,5 +224,66 @@
 #let foo(none: b) = key
 
 ---
-// Error: 11 expected comma
+// Error: 11 expected comma, found colon
 #let foo(_: 3) = none
+
+---
+// Test function reflection.
+#{
+  let f(x, (y, z), size: 1pt, ..rest) = none
+  test(f.name(), "f")
+  test((x => x).name(), none)
+  test(f.params().len(), 4)
+  test(f.params().at(0), (name: "x", named: false, has-default: false, sink: false))
+  test(f.params().at(1).name, none)
+  test(f.params().at(2), (name: "size", named: true, has-default: true, sink: false))
+  test(f.params().at(3), (name: "rest", named: false, has-default: false, sink: true))
+  test(f.with(1).params(), f.params())
+  test(((..) => none).params().at(0).name, none)
+  test(rect.name(), "rect")
+  test(rect.params().find(p => p.name == "fill").named, true)
+}
+
+---
+// Test parameter type annotations.
+#{
+  let double(n as int) = n * 2
+  test(double(3), 6)
+  test(((n as int) => n + 1)(4), 5)
+  test(double.params().first().name, "n")
+
+  // Arguments are converted to the annotated type.
+  let scale(x as float, factor: 2, ..rest) = (x * factor, rest.pos())
+  test(scale(1), (2.0, ()))
+  test(scale(1, 3, factor: 3), (3.0, (3,)))
+}
+
+---
+// Named parameters whose default is a type's function stay named.
+#{
+  let f(x, conv: str) = conv(x)
+  test(f(1), "1")
+  test(f(1, conv: repr), "1")
+
+  let length = 2pt
+  let g(size: length) = size
+  test(g(), 2pt)
+}
+
+---
+#let double(n as int) = n * 2
+// Error: 9-16 expected integer, found string
+// Hint: 9-16 parameter `n` is annotated with type `int`
+#double("hello")
+
+---
+// Error: 13-20 unknown type: integer
+#let f(n as integer) = n
+
+---
+// Error: 3-11 expected expression, found typed parameter
+#(x as int)
+
+---
+// Error: 10-18 type annotations are only allowed on parameters
+#let (a, b as int) = (1, 2)
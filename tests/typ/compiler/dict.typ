@@ -32,9 +32,46 @@
 #{
   let dict = (a: 1, b: 2)
   // Error: 11-23 dictionary does not contain key "c" and no default value was specified
+  // Warning: 7-8 unused variable: x
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
   let x = dict.at("c")
 }
 
+---
+// Test integer keys.
+#let dict = (1: "a", 0x10: "b", "2": "c")
+#test(dict, ("1": "a", "16": "b", "2": "c"))
+#test(dict.at(1), "a")
+#test(dict.at(16), "b")
+#test(dict.at(2), "c")
+#test(dict.keys(), ("1", "16", "2"))
+#test(1 in dict, true)
+#test(3 in dict, false)
+#test(dict.contains(2), true)
+#{
+  dict.insert(3, "d")
+  dict.at(1) = "e"
+  test(dict.remove(16), "b")
+  test(dict, ("1": "e", "2": "c", "3": "d"))
+}
+
+---
+// Error: 9-12 duplicate key: 1
+#(1: 1, "1": 2)
+
+---
+// Test the `map-values` and `map-keys` methods.
+#let dict = (a: 1, B: 2)
+#test(dict.map-values(v => v * 2), (a: 2, B: 4))
+#test(dict.map-values(str), (a: "1", B: "2"))
+#test(dict.map-keys(upper), (A: 1, B: 2))
+#test((ab: 1).map-keys(k => k.len()), ("2": 1))
+#test((:).map-keys(upper), (:))
+
+---
+// Error: 2-30 multiple keys are mapped to "a"
+#(a: 1, A: 2).map-keys(lower)
+
 ---
 // Test default value.
 #test((a: 1, b: 2).at("b", default: 3), 2)
@@ -60,12 +97,41 @@
 #test("c" in dict, false)
 #test(dict, (a: 3, b: 1))
 
+---
+// Test key membership.
+#{
+  let dict = (a: 1, b: none)
+  test(dict.contains("a"), true)
+  test(dict.contains("b"), true)
+  test(dict.contains("c"), false)
+  test("b" in dict, true)
+  test(1 in dict.values(), true)
+  test("c" not in dict, true)
+}
+
 ---
 // Test that removal keeps order.
 #let dict = (a: 1, b: 2, c: 3, d: 4)
 #dict.remove("b")
 #test(dict.keys(), ("a", "c", "d"))
 
+---
+// Test that removal returns the value and respects the default.
+#{
+  let dict = (a: 1, b: 2)
+  test(dict.remove("a"), 1)
+  test(dict.remove("a", default: none), none)
+  test(dict.remove("c", default: 3), 3)
+  test(dict, (b: 2))
+}
+
+---
+// Error: 3:3-3:19 dictionary does not contain key "c"
+#{
+  let dict = (a: 1)
+  dict.remove("c")
+}
+
 ---
 // Error: 24-29 duplicate key: first
 #(first: 1, second: 2, first: 3)
@@ -81,12 +147,12 @@
 
 // Identified as dictionary due to initial colon.
 // Error: 4-5 expected named or keyed pair, found integer
-// Error: 5 expected comma
-// Error: 12-16 expected identifier or string, found boolean
-// Error: 17 expected expression
+// Error: 5 expected comma, found identifier
+// Error: 12-16 expected identifier, string, or integer, found boolean
+// Error: 17 expected expression, found closing paren
 #(:1 b:"", true:)
 
-// Error: 3-8 expected identifier or string, found binary expression
+// Error: 3-8 expected identifier, string, or integer, found binary expression
 #(a + b: "hey")
 
 ---
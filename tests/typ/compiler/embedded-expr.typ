@@ -18,3 +18,51 @@
 ---
 // Error: 2-2 expected expression
 #  hello
+
+---
+// Errors in independent expressions are all reported.
+// Error: 2-9 unknown variable: missing
+#missing and
+// Error: 3-10 cannot add integer and string
+#(1 + "a")
+
+---
+// Evaluation continues with the correct scopes after an error.
+#let x = 1
+// Error: 4-9 unknown variable: inner
+#{ inner }
+#test(x, 1)
+
+---
+// Errors in bindings stop evaluation.
+// Error: 10-17 unknown variable: missing
+#let y = missing
+#z
+
+---
+// Errors in independent expressions of a code block are all reported.
+#{
+  let x = 1
+  // Error: 3-10 unknown variable: missing
+  missing
+  // Error: 4-11 cannot add integer and string
+  (1 + "a")
+  test(x, 1)
+}
+
+---
+// Errors in code block bindings stop evaluation.
+#{
+  // Error: 11-18 unknown variable: missing
+  let y = missing
+  z
+}
+
+---
+// Exceeded resource limits stop evaluation.
+// Error: 17-25 maximum function call depth exceeded
+#let f(n) = 1 + f(n + 1)
+#{
+  f(0)
+  test(1, 2)
+}
@@ -198,3 +198,69 @@
   // Hint: 3-4 try creating a new stroke with the updated field value instead
   s.thickness = 5pt
 }
+
+---
+// Test optional field access.
+#let config = (author: (name: "Anna"), editor: none)
+#test(config.author?.name, "Anna")
+#test(config.editor?.name, none)
+#test(config.editor?.name?.first, none)
+#test(none?.len(), none)
+
+---
+#let dict = (:)
+// Error: 8-13 dictionary does not contain key "title" and no default value was specified
+#dict?.title
+
+---
+// Test mutation through nested paths.
+#{
+  let data = (items: ((name: "a"), (name: "b"), (name: "c")))
+  data.items.at(2).name = "x"
+  test(data.items.at(2).name, "x")
+  data.items.last.name += "y"
+  test(data.items.at(2).name, "xy")
+  data.items.first.tags = (1, 2)
+  data.items.first.tags.at(0) = 5
+  test(data.items.first.tags, (5, 2))
+  data.items.at(1).name = data.items.at(0).name
+  test(data.items.at(1).name, "a")
+}
+
+---
+// Test assigning to the first and last items of an array.
+#{
+  let values = (1, 2, 3)
+  values.first = 0
+  values.last *= 2
+  test(values, (0, 2, 6))
+}
+
+---
+#{
+  let data = (items: (1, 2))
+  // Error: 3-19 integer does not have accessible fields
+  data.items.first.name = "x"
+}
+
+---
+#{
+  let data = (items: "abc")
+  // Error: 3-19 type string has no method `at`
+  data.items.at(0).name = 1
+}
+
+---
+#{
+  let values = ()
+  // Error: 3-15 array is empty
+  values.first = 1
+}
+
+---
+#{
+  let values = (1, 2)
+  // Error: 3-9 fields on array are not yet mutable
+  // Hint: 3-9 try creating a new array with the updated field value instead
+  values.len = 3
+}
@@ -74,25 +74,70 @@
 
 #test(joined, "a, b, c, 👩‍👩‍👦‍👦")
 
+// Reversed iteration.
+#let out = ()
+#for v in (1, 2, 3).rev() {
+  out += (v,)
+}
+#for (i, v) in (4, 5).enumerate().rev() {
+  out += (i, v)
+}
+#for c in "ab👩‍👩‍👦‍👦".rev() {
+  out += (c,)
+}
+#test(out, (3, 2, 1, 1, 5, 0, 4, "👩‍👩‍👦‍👦", "b", "a"))
+
 // Return value.
 #test(for v in "" [], none)
 #test(type(for v in "1" []), "content")
 
+---
+// Test the shape of dictionary entries.
+#let dict = (a: 1, b: 2)
+#for pair in dict {
+  test(type(pair), "array")
+  test(pair.len(), 2)
+  test(dict.at(pair.first()), pair.last())
+}
+
+#let out = ()
+#for (_, v) in dict { out.push(v) }
+#for (k, ..rest) in dict { out.push((k, rest)) }
+#test(out, (1, 2, ("a", (1,)), ("b", (2,))))
+#test(for pair in dict { (pair,) }, dict.pairs())
+
+---
+// Error: 13-14 not enough elements to destructure
+#for (a, b, c) in (x: 1) {}
+
 ---
 // Uniterable expression.
 // Error: 11-15 cannot loop over boolean
 #for v in true {}
 
 ---
-// Keys and values of strings.
-// Error: 6-12 cannot destructure values of string
-#for (k, v) in "hi" {
+// Indices and graphemes of strings.
+#let out = ()
+#for (i, c) in "ab👩‍👩‍👦‍👦" {
+  out.push((i, c))
+}
+#for (i, c) in "ab".rev() {
+  out.push((i, c))
+}
+#for _ in "xy" {
+  out.push("_")
+}
+#test(out, ((0, "a"), (1, "b"), (2, "👩‍👩‍👦‍👦"), (0, "b"), (1, "a"), "_", "_"))
+
+---
+// Error: 13-14 not enough elements to destructure
+#for (i, c, d) in "hi" {
   dont-care
 }
 
 ---
 // Destructuring without parentheses.
-// Error: 7 expected keyword `in`
+// Error: 7 expected keyword `in`, found comma
 // Hint: 7 did you mean to use a destructuring pattern?
 #for k, v in (a: 4, b: 5) {
   dont-care
@@ -104,7 +149,7 @@
 // Error: 5 expected identifier
 #for//
 
-// Error: 6 expected identifier
+// Error: 6 expected identifier, found closing brace
 #{for}
 
 // Error: 7 expected keyword `in`
@@ -120,12 +165,49 @@
 #for
 v in iter {}
 
-// Error: 6 expected identifier
-// Error: 10 expected block
+// Error: 6 expected identifier, found string
+// Error: 10 expected block, found identifier
 A#for "v" thing
 
-// Error: 5 expected identifier
+// Error: 5 expected identifier, found string
 #for "v" in iter {}
 
-// Error: 7 expected keyword `in`
+// Error: 7 expected keyword `in`, found plus
 #for a + b in iter {}
+
+---
+// Test else clauses of for loops.
+#let find(values, target) = {
+  for (i, v) in values.enumerate() {
+    if v == target {
+      return i
+    }
+  } else {
+    none
+  }
+}
+
+#test(find((1, 2, 3), 2), 1)
+#test(find((1, 2, 3), 4), none)
+
+#let out = for v in (1, 2, 3) {
+  (v,)
+} else {
+  ("done",)
+}
+#test(out, (1, 2, 3, "done"))
+
+#let out = for v in (1, 2, 3) {
+  if v == 2 { break }
+  (v,)
+} else {
+  ("done",)
+}
+#test(out, (1,))
+
+// The else block is also evaluated for empty collections.
+#test(for v in () { v } else { "empty" }, "empty")
+
+// The loop variable is not in scope in the else block.
+#let v = "outer"
+#test(for v in (1,) {} else { v }, "outer")
@@ -109,7 +109,7 @@
 // Error: 4 expected expression
 #if
 
-// Error: 5 expected expression
+// Error: 5 expected expression, found closing brace
 #{if}
 
 // Error: 6 expected block
@@ -124,11 +124,11 @@
 x {}
 
 // Should output `something`.
-// Error: 6 expected block
+// Error: 6 expected block, found identifier
 #if x something
 
 // Should output `A thing.`
-// Error: 19 expected block
+// Error: 19 expected block, found identifier
 A#if false {} else thing
 
 #if a []else [b]
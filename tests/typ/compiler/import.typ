@@ -99,7 +99,7 @@
 #import () => {5}: x
 
 ---
-// Error: 9-10 expected path, module or function, found integer
+// Error: 9-10 expected path, module, function or dictionary, found integer
 #import 5: something
 
 ---
@@ -110,6 +110,18 @@
 // Error: 9-20 file not found (searched at typ/compiler/lib/0.2.1)
 #import "lib/0.2.1"
 
+---
+// Error: 9-33 cannot access file outside of the project root
+#import "../../../../secret.typ"
+
+---
+// Error: 9-25 cannot access file outside of the project root
+#import "/../secret.typ"
+
+---
+// Parent directories are fine as long as they stay within the root.
+#import "../compiler/module.typ": a
+
 ---
 // Some non-text stuff.
 // Error: 9-27 file is not valid utf-8
@@ -149,7 +161,7 @@ This is never reached.
 #import "module.typ": a, b, c,,
 
 ---
-// Error: 2:2 expected semicolon or line break
+// Error: 2:2 expected semicolon or line break, found identifier
 #import "module.typ
 "stuff
 
@@ -160,7 +172,7 @@ This is never reached.
 
 ---
 // An item after a star.
-// Error: 24 expected semicolon or line break
+// Error: 24 expected semicolon or line break, found comma
 #import "module.typ": *, a
 
 ---
@@ -169,5 +181,87 @@ This is never reached.
 #import "": a: 1
 
 ---
-// Error: 14 expected comma
+// Error: 14 expected comma, found identifier
 #import "": a b
+
+---
+// Test importing from a dictionary.
+#let config = (size: 12pt, name: "Typst")
+#import config: size
+#test(size, 12pt)
+
+#import config: *
+#test(name, "Typst")
+
+#import dictionary(calc): pi
+#test(pi, calc.pi)
+
+---
+// Error: 9-15 cannot import a dictionary without specifying items
+#import (a: 1)
+
+---
+// Error: 17-18 unresolved import
+#import (a: 1): b
+
+---
+// A wildcard import with exclusions.
+#let d = "mine"
+#import "module.typ": * except (d, item)
+#test(d, "mine")
+#test(b, 1)
+
+// Also in code mode and across lines.
+#{
+  let push = none
+  import "module.typ": * except (
+    push,
+    value,
+  )
+  test(push, none)
+  test(c, 2)
+}
+
+// Exclusions from a dictionary.
+#import (a: 1, b: 2, c: 3): * except (b,)
+#test(a, 1)
+#test(c, 3)
+
+---
+// Error: 33-40 unresolved exclusion
+#import "module.typ": * except (missing)
+
+---
+// Error: 33-34 unexpected integer
+#import "module.typ": * except (1)
+
+---
+// Importing nested items with paths.
+#let theme = (colors: (primary: blue, secondary: red), size: 10pt)
+#import theme: colors.primary, colors.secondary as accent, size
+#test(primary, blue)
+#test(accent, red)
+#test(size, 10pt)
+
+#import sym: arrow.r as right
+#test(right, sym.arrow.r)
+
+// Renaming a plain item.
+#import "module.typ": b as bee
+#test(bee, 1)
+
+---
+// Error: 24-25 dictionary does not contain key "c" and no default value was specified
+#import (a: (b: 1)): a.c
+
+---
+// Error: 26-27 cannot access fields on type integer
+#import (a: (b: 1)): a.b.c
+
+---
+// Error: 17-18 unresolved import
+#import (a: 1): b.c
+
+---
+// Error: 25 expected identifier
+#import "module.typ": a.
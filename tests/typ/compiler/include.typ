@@ -17,6 +17,8 @@
 ---
 #{
   // Error: 19-38 file not found (searched at typ/compiler/modules/chap3.typ)
+  // Warning: 7-8 unused variable: x
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
   let x = include "modules/chap3.typ"
 }
 
@@ -28,5 +30,5 @@
 #name
 
 ---
-// Error: 18 expected semicolon or line break
+// Error: 18 expected semicolon or line break, found identifier
 #include "hi.typ" Hi
@@ -50,6 +50,8 @@ _Visible_
 ---
 // Test that label only works within one content block.
 #show <strike>: strike
+// Warning: 13-21 label is not attached to anything
+// Hint: 13-21 labels attach to the preceding element in the same content block
 *This is* #[<strike>] *protected.*
 *This is not.* <strike>
 
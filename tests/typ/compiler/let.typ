@@ -204,29 +204,29 @@ Three
 // Error: 5 expected identifier
 #let
 
-// Error: 6 expected identifier
+// Error: 6 expected identifier, found closing brace
 #{let}
 
-// Error: 5 expected identifier
-// Error: 5 expected semicolon or line break
+// Error: 5 expected identifier, found string
+// Error: 5 expected semicolon or line break, found string
 #let "v"
 
-// Error: 7 expected semicolon or line break
+// Error: 7 expected semicolon or line break, found integer
 #let v 1
 
 // Error: 9 expected expression
 #let v =
 
-// Error: 5 expected identifier
-// Error: 5 expected semicolon or line break
+// Error: 5 expected identifier, found string
+// Error: 5 expected semicolon or line break, found string
 #let "v" = 1
 
 // Terminated because expression ends.
-// Error: 12 expected semicolon or line break
+// Error: 12 expected semicolon or line break, found identifier
 #let v4 = 4 Four
 
 // Terminated by semicolon even though we are in a paren group.
-// Error: 18 expected expression
+// Error: 18 expected expression, found semicolon
 // Error: 11-12 unclosed delimiter
 #let v5 = (1, 2 + ; Five
 
@@ -259,3 +259,38 @@ Three
 
 // Error: 15 expected expression
 #let func(x) =
+
+---
+// Test type annotations.
+// Ref: false
+#let a as int = 1
+#let b as float = 2.5
+#let c as relative = 50% + 1pt
+#let d as any = none
+#let e as content = [Hi]
+#test(a + b, 3.5)
+#test(c, 50% + 1pt)
+#test(e, [Hi])
+
+---
+// Integers are converted to floats.
+// Ref: false
+#let f as float = 1
+#test(type(f), "float")
+#test(f, 1.0)
+
+---
+// Error: 17-24 expected integer, found string
+#let x as int = "hello"
+
+---
+// Error: 8-14 expected integer, found none
+#let w as int
+
+---
+// Error: 11-18 unknown type: integer
+#let y as integer = 1
+
+---
+// Error: 10 expected identifier, found equals sign
+#let z as = 1
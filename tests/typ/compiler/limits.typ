@@ -0,0 +1,40 @@
+// Test the iteration and call limits of evaluations.
+// Ref: false
+
+---
+// Loops in callbacks count towards the caller's limit.
+#test(sandbox("range(10).map(_ => { for _ in range(50) {} }).len()", 1000), 10)
+
+---
+// Error: 10-58 maximum number of loop iterations exceeded
+#sandbox("range(100).map(_ => { for _ in range(50) {} })", 1000)
+
+---
+// What a call used counts towards its caller's budget.
+// Error: 10-79 maximum number of loop iterations exceeded
+#sandbox("{ for _ in range(80) {}; let f() = { for _ in range(30) {} }; f() }", 100)
+
+---
+// Calls count, too, even without loops.
+// Error: 10-46 maximum number of function calls exceeded
+#sandbox("{ let f(n) = n; range(50).map(f) }", 40)
+
+---
+// Error: 10-55 maximum number of function calls exceeded
+#sandbox("{ let f(n) = if n > 0 { f(n - 1) }; f(50) }", 40)
+
+---
+// Nested calls share one budget instead of each getting a fresh one.
+// Error: 10-82 maximum number of loop iterations exceeded
+#sandbox("{ let f(n) = { for _ in range(20) {}; if n > 0 { f(n - 1) } }; f(10) }", 100)
+
+---
+// Recursion that fans out stops at the first exceeded limit.
+// Error: 10-55 maximum function call depth exceeded
+#sandbox("{ let f(n) = { f(n + 1); f(n + 1) }; f(0) }", 100)
+
+---
+// Failed calls count towards their caller's budget, too.
+// Error: 10-92 unknown variable: missing
+// Error: 10-92 maximum number of function calls exceeded
+#sandbox("{ let f(n) = { if n < 60 { f(n + 1); f(n + 1); none } else { missing } }; f(0) }", 100)
@@ -13,6 +13,18 @@
   test(matrix, (((1,), (2,)), ((3, 5), (4,))))
 }
 
+---
+// Test return values of mutating methods on indexed values.
+#{
+  let config = (items: (1, 2, 3), extra: (a: 1))
+  let last = config.items.pop()
+  let first = config.at("items").remove(0)
+  let a = config.extra.remove("a")
+  test((first, last, a), (1, 3, 1))
+  test(config, (items: (2,), extra: (:)))
+  test(config.items.insert(0, 1), none)
+}
+
 ---
 // Test multiline chain in code block.
 #{
@@ -198,3 +210,48 @@
 #test(2deg.deg(), 2.0)
 #test(2.94deg.deg(), 2.94)
 #test(0rad.deg(), 0.0)
+
+---
+// Test integer methods.
+#test((5).abs(), 5)
+#test((-5).abs(), 5)
+#test((-7).floor(), -7)
+#test((7).ceil(), 7)
+#test((-7).trunc(), -7)
+#test((1234).round(), 1234)
+#test((1234).round(digits: -2), 1200)
+#test((1250).round(digits: -2), 1300)
+#test((-1250).round(digits: -2), -1300)
+#test((-1249).round(digits: -2), -1200)
+#test((15).round(digits: -20), 0)
+
+---
+// Test float methods.
+#test((-2.5).abs(), 2.5)
+#test((2.7).floor(), 2)
+#test((-2.3).floor(), -3)
+#test((2.3).ceil(), 3)
+#test((-2.7).ceil(), -2)
+#test((2.7).trunc(), 2)
+#test((-2.7).trunc(), -2)
+#test((2.5).round(), 3.0)
+#test((-2.5).round(), -3.0)
+#test((0.5).round(), 1.0)
+#test((3.14159).round(digits: 2), 3.14)
+#test((-3.14159).round(digits: 3), -3.142)
+
+---
+// Error: 2-18 cannot convert NaN to an integer
+#calc.nan.floor()
+
+---
+// Error: 2-17 number too large
+#calc.inf.ceil()
+
+---
+// Error: 2-16 number too large
+#(1e19).trunc()
+
+---
+// Error: 2-34 the result is too large
+#(-9223372036854775807 - 1).abs()
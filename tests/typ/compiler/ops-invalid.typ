@@ -2,15 +2,15 @@
 // Ref: false
 
 ---
-// Error: 4 expected expression
+// Error: 4 expected expression, found closing paren
 #(-)
 
 ---
-// Error: 10 expected expression
+// Error: 10 expected expression, found closing brace
 #test({1+}, 1)
 
 ---
-// Error: 10 expected expression
+// Error: 10 expected expression, found closing brace
 #test({2*}, 2)
 
 ---
@@ -178,6 +178,12 @@
 #test([a] == [a], true)
 #test(grid[a] == grid[a], true)
 #test(grid[a] == grid[b], false)
+#test([a *b*] == [a *b*], true)
+#test([a *b*] == [a _b_], false)
+#test([#set text(red); a] == [#set text(red); a], true)
+#test([#set text(red); a] == [#set text(blue); a], false)
+#test([#set text(red); a] == [a], false)
+#test([#show "a": [b]; a] == [#show "a": [b]; a], true)
 
 ---
 // Test comparison operators.
@@ -205,6 +211,23 @@
 #(x = "some")   #test(x, "some")
 #(x += "thing") #test(x, "something")
 
+---
+// Test logical assignment operators.
+#let x = true
+#(x and= false) #test(x, false)
+#(x or= true)   #test(x, true)
+#(x or= false)  #test(x, true)
+
+// Short-circuiting.
+#(x or= dont-care)  #test(x, true)
+#(x = false)
+#(x and= dont-care) #test(x, false)
+
+---
+#let x = true
+// Error: 3-11 cannot apply 'and' to boolean and integer
+#(x and= 1)
+
 ---
 // Test destructuring assignments.
 
@@ -267,7 +290,20 @@
 /* fun comment? */ in "abc", false)
 
 ---
-// Error: 10 expected keyword `in`
+// Test that `in` uses the same equality as `==`.
+#test((1, 2) in ((1, 2), (3, 4)), true)
+#test((1, 3) in ((1, 2), (3, 4)), false)
+#test((a: (1, 2)) in ((a: (1.0, 2.0)),), true)
+#test(1.0 in (1, 2), true)
+#test(10pt in (10pt + 0%,), true)
+#test([*a*] in ([*a*], [b]), true)
+#test([a] in ([*a*],), false)
+#test(((1, 2), (3, 4)).contains((3, 4)), true)
+#test(((1, 2), (3, 4)).contains((3, 4.0)), true)
+#test(((1, 2),).contains(((1, 2),)), false)
+
+---
+// Error: 10 expected keyword `in`, found closing paren
 #("a" not)
 
 ---
@@ -287,3 +323,11 @@
 #let inc2 = inc.with(y: 2)
 #test(inc2(2), 4)
 #test(inc2(2, y: 4), 6)
+
+// Bound positional arguments come first, bound named ones can be overridden.
+#let collect(..args) = args
+#let partial = collect.with(1, key: 2)
+#test(partial(3).pos(), (1, 3))
+#test(partial(3).named(), (key: 2))
+#test(partial(3, key: 4).named(), (key: 4))
+#test(partial.with(5)(6).pos(), (1, 5, 6))
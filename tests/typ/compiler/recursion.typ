@@ -40,3 +40,33 @@
 #let f(x) = "hello"
 #let f(x) = if x != none { f(none) } else { "world" }
 #test(f(1), "world")
+
+---
+// Self-calls in tail position don't count towards the call depth.
+#let count(n, acc: 0) = if n == 0 { acc } else { count(n - 1, acc: acc + 1) }
+#test(count(1000), 1000)
+
+---
+// Output in front of a tail call is kept.
+#let stars(n) = if n > 0 { "*"; stars(n - 1) } else { "" }
+#test(stars(200), "*" * 200)
+
+---
+// Returned self-calls are in tail position, too.
+#let find(xs, x, i: 0) = {
+  if i >= xs.len() { return none }
+  if xs.at(i) == x { return i }
+  return find(xs, x, i: i + 1)
+}
+#test(find(range(500), 321), 321)
+#test(find((1, 2), 3), none)
+
+---
+// Error: 12-15 function calls itself with the same arguments
+#let f() = f()
+#f()
+
+---
+// Self-calls in tail position count towards the call limit.
+// Error: 10-41 maximum number of function calls exceeded
+#sandbox("{ let f(n) = f(n + 1); f(0) }", 100)
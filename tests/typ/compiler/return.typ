@@ -50,6 +50,7 @@
 
 #for x in range(5) {
   // Error: 3-9 cannot return outside of function
+  // Hint: 3-9 `return` can only be used in the body of a function
   return
 }
 
@@ -80,3 +81,10 @@
 ]
 
 #test(f(), "nope")
+
+---
+// Test that a return at the top level is reported where it appears.
+#let x = 1
+// Error: 2-13 cannot return outside of function
+// Hint: 2-13 `return` can only be used in the body of a function
+#return x + 1
@@ -0,0 +1,31 @@
+// Test sandboxed evaluation.
+// Ref: false
+
+---
+#test(sandbox("1 + 2", 100), 3)
+#test(sandbox("{ let spin(_) = { let i = 0; while i < 500 { i += 1 } }; range(2).map(spin).len() }", 1100), 2)
+
+---
+// Error: 10-33 cannot access files in this context
+#sandbox("import \"module.typ\"", 100)
+
+---
+// A loop inside a function called by `map` counts towards the sandbox's limit.
+// Error: 10-90 maximum number of loop iterations exceeded
+#sandbox("{ let spin(_) = { let i = 0; while i < 500 { i += 1 } }; range(10).map(spin) }", 1000)
+
+---
+// Closures created in a sandbox keep its limits when called later.
+// Error: 16-54 maximum number of loop iterations exceeded
+#show: sandbox("it => { for _ in range(200) {}; it }", 100)
+Text
+
+---
+// Error: 16-53 cannot access files in this context
+#show: sandbox("it => { import \"module.typ\"; it }", 100)
+Text
+
+---
+// Nested evaluations inherit the sandbox's limits.
+// Error: 10-44 maximum number of loop iterations exceeded
+#sandbox("eval(\"for _ in range(200) {}\")", 100)
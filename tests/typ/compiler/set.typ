@@ -62,5 +62,36 @@ Hello *#x*
 #set text(red) if 1 + 2
 
 ---
-// Error: 12-26 set is only allowed directly in code and content blocks
+// Ref: false
+// Warning: 8-9 unused variable: x
+// Hint: 8-9 prefix the name with an underscore to silence this warning
 #{ let x = set text(blue) }
+
+---
+// Test set rules as values.
+// Ref: false
+#let big = set text(size: 14pt)
+#let loud = big + set text(red)
+#test(type(big), "styles")
+#test(type(loud), "styles")
+
+#let body = [Loud].styled(loud)
+#test(body.func(), [#set text(red); Loud].func())
+
+---
+// Test applying stored set rules with show rules.
+// Ref: false
+#let emph-style = set text(fill: blue) + set text(style: "italic")
+#show heading: emph-style
+= Blue heading
+Normal text.
+
+#[
+  #show: emph-style
+  Blue text.
+]
+
+---
+// Error: 2:3-2:8 cannot add styles and integer
+#let s = set text(red)
+#(s + 1)
@@ -18,3 +18,10 @@ a~b
 ---
 #set text(font: "Roboto")
 A... vs #"A..."
+
+---
+// Enumerations in prose aren't shorthands.
+// Ref: false
+#test([(c)].text, "(c)")
+#test([(r)].text, "(r)")
+#test([(tm)].text, "(tm)")
@@ -30,6 +30,7 @@ Ignored
 
 ---
 // Error: 4-19 show is only allowed directly in code and content blocks
+// Hint: 4-19 only show rules with a selector can be used as values
 #((show: body => 2) * body)
 
 ---
@@ -91,7 +91,7 @@ Hey
 #show upper: it => {}
 
 ---
-// Error: 16-20 expected content or function, found integer
+// Error: 16-20 expected content, function, or styles, found integer
 #show heading: 1234
 = Heading
 
@@ -100,5 +100,51 @@ Hey
 #show red: []
 
 ---
-// Error: 7-25 show is only allowed directly in code and content blocks
+// Error: 3-25 cannot add integer and styles
 #(1 + show heading: none)
+
+---
+// Test show rules as values.
+// Ref: false
+#let rule = show heading: it => [Heading: #it.body]
+#test(type(rule), "styles")
+
+#let theme = rule + set text(red) + show strong: emph
+#test(type(theme), "styles")
+
+#let themed(body) = body.styled(theme)
+#themed[= Title]
+
+#[
+  #show: theme
+  = Another
+  *Strong*
+]
+
+---
+// Test that a combined bundle applies all of its rules.
+// Ref: false
+#let seen = state("seen", ())
+#let bundle = (
+  set heading(numbering: "I.")
+    + (show heading: it => seen.update(s => s + (it.numbering,)))
+    + (show strong: it => seen.update(s => s + (it.body,)))
+)
+
+#[
+  #show: bundle
+  = Title
+  *Bold*
+]
+
+= Plain
+*Plain*
+
+#locate(loc => test(seen.final(loc), ("I.", [Bold])))
+
+---
+// Test returning recipes from a configuration function.
+// Ref: false
+#let config(color) = show heading: set text(color)
+#show: config(blue)
+= Blue
@@ -48,6 +48,25 @@
   test(tostr(a: 1, ..more, b: 2), "(a: 1, c: 3, d: 4, b: 2)")
 }
 
+---
+// Test spreading in the middle of a call.
+#{
+  let middle = (2, 3)
+  let f(..args) = args.pos()
+  test(f(1, ..middle, 4), (1, 2, 3, 4))
+  test(f(..middle, 1, ..middle), (2, 3, 1, 2, 3))
+}
+
+---
+// Test that later named arguments win over spread ones and vice versa.
+#{
+  let f(a: 0, b: 0) = (a, b)
+  let dict = (a: 1, b: 2)
+  test(f(..dict, a: 3), (3, 2))
+  test(f(a: 3, ..dict), (1, 2))
+  test(f(b: 5, ..dict, b: 4), (1, 4))
+}
+
 ---
 // None is spreadable.
 #let f() = none
@@ -69,8 +88,8 @@
 #let f(..true) = none
 
 ---
-// Error: 13-16 only one argument sink is allowed
-#let f(..a, ..b) = none
+// Error: 18-21 only one positional and one named argument sink are allowed
+#let f(..a, ..b, ..c) = none
 
 ---
 // Test spreading into array and dictionary.
@@ -121,3 +140,60 @@
   // Error: 4-10 missing argument: d
   f(1, 2)
 }
+
+---
+// Test inspecting arguments.
+#{
+  let f(..args) = args
+  let args = f(1, 2, fill: red, size: 3pt)
+  test(args.at(0), 1)
+  test(args.at(-1), 2)
+  test(args.at("fill"), red)
+  test(args.at(2, default: none), none)
+  test(args.at("stroke", default: 1pt), 1pt)
+  test(args.named("size"), 3pt)
+  test(args.named("stroke", default: none), none)
+  test(args.named(), (fill: red, size: 3pt))
+}
+
+---
+// Test forwarding filtered arguments.
+#{
+  let g(..args) = repr(args)
+  let f(..args) = {
+    let named = (:)
+    for (key, value) in args.named() {
+      if key != "debug" {
+        named.insert(key, value)
+      }
+    }
+    g(..args.pos().filter(x => x > 1), ..named)
+  }
+  test(f(1, 2, 3, debug: true, size: 4), "(2, 3, size: 4)")
+}
+
+---
+// Error: 25-35 array index out of bounds (index: 1, len: 1) and no default value was specified
+#let f(..args) = args; #f(1).at(1)
+
+---
+// Error: 25-43 dictionary does not contain key "fill" and no default value was specified
+#let f(..args) = args; #f(1).named("fill")
+
+---
+// Error: 25-38 expected integer or string, found boolean
+#let f(..args) = args; #f(1).at(true)
+
+---
+// Test separate positional and named argument sinks.
+#{
+  let f(a, ..args, ..options) = (a, args, options)
+  test(f(1), (1, (), (:)))
+  test(f(1, 2, 3, fill: red), (1, (2, 3), (fill: red)))
+
+  let g(..args, b: 0, ..) = (args, b)
+  test(g(1, 2, b: 3, c: 4), ((1, 2), 3))
+
+  let h(.., ..options) = options
+  test(h(1, x: 2), (x: 2))
+}
@@ -4,6 +4,25 @@
 ---
 // Test the `len` method.
 #test("Hello World!".len(), 12)
+#test("Hello World!".len(unit: "bytes"), 12)
+#test("🏳️‍🌈A".len(), 2)
+#test("🏳️‍🌈A".len(unit: "graphemes"), 2)
+#test("🏳️‍🌈A".len(unit: "codepoints"), 5)
+#test("🏳️‍🌈A".len(unit: "bytes"), 15)
+#test("e\u{301}".len(), 1)
+#test("e\u{301}".len(unit: "codepoints"), 2)
+
+---
+// The length in bytes is a valid index for non-ASCII text.
+#let s = "Grüße 🏳️‍🌈"
+#test(s.len(), 7)
+#test(s.slice(0, s.len(unit: "bytes")), s)
+#test(s.slice(0, s.len(unit: "bytes") - "🏳️‍🌈".len(unit: "bytes")), "Grüße ")
+#test(s.at(s.len(unit: "bytes"), default: none), none)
+
+---
+// Error: 20-27 expected "bytes", "codepoints", or "graphemes"
+#"Hello".len(unit: "words")
 
 ---
 // Test the `first` and `last` methods.
@@ -55,11 +74,119 @@
 #"🏳️‍🌈".slice(0, -1)
 
 ---
-// Test the `clusters` and `codepoints` methods.
-#test("abc".clusters(), ("a", "b", "c"))
+// Test the `rev` method.
+#test("abc".rev(), "cba")
+#test("".rev(), "")
+#test("a🏳️‍🌈b".rev(), "b🏳️‍🌈a")
+#test("e\u{301}x".rev(), "xe\u{301}")
+
+---
+// Test the `lower`, `upper`, and `title` methods.
+#test("ÄBC def".lower(), "äbc def")
+#test("ábc Def".upper(), "ÁBC DEF")
+#test("ß".upper(), "SS")
+#test("".upper(), "")
+#test("hello wörld".title(), "Hello Wörld")
+#test("it's a mIXed-case".title(), "It's A MIXed-Case")
+#test("1st place, ÿes".title(), "1st Place, Ÿes")
+
+---
+// Test the `to-int` and `to-float` methods.
+#test("42".to-int(), 42)
+#test("-17".to-int(), -17)
+#test("ff".to-int(base: 16), 255)
+#test("-101".to-int(base: 2), -5)
+#test("zz".to-int(base: 36), 1295)
+#test(" 12\n".to-int(trim: true), 12)
+#test("2.5".to-float(), 2.5)
+#test("-1e3".to-float(), -1000.0)
+#test("7".to-float(), 7.0)
+#test(" 0.5 ".to-float(trim: true), 0.5)
+
+---
+// Error: 2-16 invalid integer: 1 2
+#"1 2".to-int()
+
+---
+// Error: 2-23 invalid integer in base 2: 102
+#"102".to-int(base: 2)
+
+---
+// Error: 2-22 base must be between 2 and 36
+#"1".to-int(base: 37)
+
+---
+// Error: 2-18 invalid float: abc
+#"abc".to-float()
+
+---
+// Test the `format` method.
+#test("{} + {} = {}".format(1, 2, 3), "1 + 2 = 3")
+#test("{1}{0}{1}".format("a", "b"), "bab")
+#test("{name} is {age}".format(name: "Anna", age: 30), "Anna is 30")
+#test("{} and {x}".format(1.5, x: none), "1.5 and none")
+#test("{:05}|{:04}|{:4}|{:4}|".format(42, -7, 2.5, "ab"), "00042|-007| 2.5|ab  |")
+#test("{{{}}}".format("x"), "{x}")
+#test("}}{{".format(), "}{")
+
+---
+// Error: 2-18 unclosed placeholder in format string
+#"{a{b}".format()
+
+---
+// Error: 2-15 unmatched closing brace in format string
+#"a}".format()
+
+---
+// Error: 2-18 format string has more placeholders than arguments
+#"{}{}".format(1)
+
+---
+// Error: 2-17 format string refers to missing argument "x"
+#"{x}".format(1)
+
+---
+// Error: 2-24 zero padding is only supported for numbers
+#"{:03}".format("text")
+
+---
+// Test the `chars`, `clusters` and `codepoints` methods.
+#test("abc".chars(), ("a", "b", "c"))
+#test("a\u{0300}b".chars(), ("a\u{0300}", "b"))
 #test("abc".clusters(), ("a", "b", "c"))
 #test("🏳️‍🌈!".clusters(), ("🏳️‍🌈", "!"))
-#test("🏳️‍🌈!".codepoints(), ("🏳", "\u{fe0f}", "\u{200d}", "🌈", "!"))
+#test("🏳️‍🌈!".chars(), "🏳️‍🌈!".clusters())
+#test("abc".codepoints(), (97, 98, 99))
+#test("a\u{0300}".codepoints(), (0x61, 0x300))
+#test("🏳️‍🌈!".codepoints(), (0x1f3f3, 0xfe0f, 0x200d, 0x1f308, 0x21))
+#test("".codepoints(), ())
+
+---
+// Test the `graphemes`, `words` and `sentences` methods.
+#test("🏳️‍🌈!".graphemes(), "🏳️‍🌈!".clusters())
+#test("".words(), ())
+#test("Hello, world!".words(), ("Hello", ",", "world", "!"))
+#test("Hello, world!".words(whitespace: true), ("Hello", ",", " ", "world", "!"))
+#test("One two  three".words().len(), 3)
+#test("can't stop".words(), ("can't", "stop"))
+#test("Hi there. How are you?".sentences(), ("Hi there. ", "How are you?"))
+#test("  ".sentences(), ())
+#test("  ".sentences(whitespace: true), ("  ",))
+
+---
+// Error: 26-27 expected boolean, found integer
+#"a b".words(whitespace: 1)
+
+---
+// The characters match the iteration of a for loop.
+#{
+  let s = "ne\u{301}e"
+  let items = ()
+  for c in s { items.push(c) }
+  test(s.chars(), items)
+  test(s.chars().len(), 3)
+  test(s.codepoints().len(), 4)
+}
 
 ---
 // Test the `contains` method.
@@ -81,6 +208,18 @@
 #test("Typst".ends-with(regex("\d*")), true)
 #test("Typst".ends-with(regex("\d+")), false)
 #test("Typ12".ends-with(regex("\d+")), true)
+#test("aaa".ends-with(regex("a.")), true)
+#test("aab".ends-with(regex("ab|a")), true)
+#test("abc".starts-with(regex("b|ab")), true)
+
+---
+// Every string contains, starts and ends with the empty string.
+#test("".starts-with(""), true)
+#test("".ends-with(""), true)
+#test("abc".contains(""), true)
+#test("abc".starts-with(""), true)
+#test("abc".ends-with(regex("")), true)
+#test("".contains("a"), false)
 
 ---
 // Test the `find` and `position` methods.
@@ -89,6 +228,22 @@
 #test("Hello World".position("World"), 6)
 #test("It's 12:13 now".find(date), "12:13")
 #test("It's 12:13 now".position(date), 5)
+#test("Hello World".position("x"), none)
+
+---
+// Test the `rfind` and `rposition` methods.
+#let date = regex("\d{2}:\d{2}")
+#test("a-b-c".rfind("-"), "-")
+#test("a-b-c".rposition("-"), 3)
+#test("a-b-c".rposition("x"), none)
+#test("From 12:13 to 14:15".rfind(date), "14:15")
+#test("From 12:13 to 14:15".rposition(date), 14)
+#test("no dates".rfind(date), none)
+#test("äbä".rposition("ä"), 3)
+#test("aaa".rposition("aa"), 1)
+#test("aaa".rposition(regex("aa")), 0)
+#test("aaa".rfind(regex("a+")), "aaa")
+#test("a1 b22 c333".rfind(regex("\d+")), "333")
 
 ---
 // Test the `match` method.
@@ -213,3 +368,18 @@
 ---
 // Error: 2-2:1 unclosed string
 #"hello\"
+
+---
+// Test the `repeat` method.
+#test("ab".repeat(3), "ababab")
+#test("ab".repeat(0), "")
+#test("".repeat(5), "")
+#test("a".repeat(3, with: "-"), "a-a-a")
+#test("a".repeat(1, with: "-"), "a")
+#test("a".repeat(0, with: "-"), "")
+#test("ab".repeat(2), "ab" * 2)
+#test(([a] * 3).children.len(), 3)
+
+---
+// Error: 2-16 cannot repeat this string -1 times
+#"a".repeat(-1)
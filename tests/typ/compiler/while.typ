@@ -46,7 +46,7 @@
 // Error: 7 expected expression
 #while
 
-// Error: 8 expected expression
+// Error: 8 expected expression, found closing brace
 #{while}
 
 // Error: 9 expected block
@@ -56,5 +56,26 @@
 #while
 x {}
 
-// Error: 9 expected block
+// Error: 9 expected block, found identifier
 #while x something
+
+---
+// Test else clauses of while loops.
+// Ref: false
+#let i = 0
+#let out = while i < 3 {
+  i += 1
+  (i,)
+} else {
+  ("done",)
+}
+#test(out, (1, 2, 3, "done"))
+
+#let i = 0
+#let out = while true {
+  i += 1
+  if i == 2 { break }
+} else {
+  "unreachable"
+}
+#test(out, none)
@@ -8,9 +8,13 @@
 #test(int(10), 10)
 #test(int("150"), 150)
 #test(int(10 / 3), 3)
+#test(int(3.7), 3)
+#test(int(-3.7), -3)
 #test(float(10), 10.0)
 #test(float(50% * 30%), 0.15)
 #test(float("31.4e-1"), 3.14)
+#test(float("2.5"), 2.5)
+#test(float(true), 1.0)
 #test(type(float(10)), "float")
 
 ---
@@ -29,6 +33,14 @@
 // Error: 6-12 invalid integer: nope
 #int("nope")
 
+---
+// Error: 6-14 cannot convert NaN to an integer
+#int(calc.nan)
+
+---
+// Error: 6-10 number too large
+#int(1e19)
+
 ---
 // Error: 8-15 invalid float: 1.2.3
 #float("1.2.3")
@@ -100,6 +112,23 @@
 #test(calc.exp(2), calc.pow(calc.e, 2))
 #test(calc.ln(10), calc.log(10, base: calc.e))
 
+---
+// Test the trigonometric functions with angles and numbers.
+#test(calc.sin(90deg), 1.0)
+#test(calc.cos(0deg), 1.0)
+#test(calc.sin(0), 0.0)
+#test(calc.cos(180deg), -1.0)
+#test(calc.tan(0deg), 0.0)
+#test(calc.sin(calc.pi / 2), calc.sin(90deg))
+
+---
+// Error: 10-11 value must be strictly positive
+#calc.ln(0)
+
+---
+// Error: 10-12 value must be strictly positive
+#calc.ln(-1)
+
 ---
 // Error: 10-16 zero to the power of zero is undefined
 #calc.pow(0, 0)
@@ -223,3 +223,53 @@
 ---
 // Error: 26-36 failed to format datetime in the requested format
 #datetime.today().display("[hour]")
+
+---
+// Test conversion of modules to dictionaries.
+#let d = dictionary(calc)
+#test(type(d), "dictionary")
+#test(d.pi, calc.pi)
+#test((d.abs)(-3), 3)
+#test("abs" in d, true)
+
+---
+// Test conversion of pairs to dictionaries.
+#let d = (a: 1, b: 2)
+#test(dictionary(d.pairs()), d)
+#test(dictionary(d), d)
+#test(dictionary(()), (:))
+#test(dictionary((("a", 1), ("a", 2))), (a: 2))
+#test(dictionary(d.pairs().map(((k, v)) => (k, v * 10))), (a: 10, b: 20))
+
+---
+// Test creating dictionaries from named arguments.
+#test(dictionary(), (:))
+#test(dictionary(a: 1, b: 2), (a: 1, b: 2))
+#test(dictionary(..(a: 1), b: 2), (a: 1, b: 2))
+
+---
+// Error: 21-25 unexpected argument: b
+#dictionary((a: 1), b: 2)
+
+---
+// Error: 14-15 unexpected argument
+#array((1,), 2)
+
+---
+// Error: 13-17 expected module, dictionary, or array, found integer
+#dictionary(1234)
+
+---
+// Error: 13-22 pair must have exactly two items, found 1
+#dictionary((("a",),))
+
+---
+// Test zipping keys and values into a dictionary.
+#let header = ("name", "age", "city")
+#test(dictionary(header.zip(("Ada", 36))), (name: "Ada", age: 36))
+#test(dictionary((1, 2.5, <x>).zip((1, 2, 3))), ("1": 1, "2.5": 2, x: 3))
+#test(dictionary(("a", "b", "a").zip((1, 2, 3))), (a: 3, b: 2))
+
+---
+// Error: 13-25 expected integer, float, label, bytes, or string, found none
+#dictionary(((none, 2),))
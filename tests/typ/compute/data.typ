@@ -6,6 +6,17 @@
 #let data = read("/files/hello.txt")
 #test(data, "Hello, world!\n")
 
+---
+// Test reading files as bytes and relative to the current file.
+#let data = read("/files/hello.txt", encoding: none)
+#test(type(data), "bytes")
+#test(data.len(), 14)
+#test(str(data), read("../../files/hello.txt"))
+
+---
+// Error: 18-34 cannot access file outside of the project root
+#let data = read("/../hello.txt")
+
 ---
 // Error: 18-38 file not found (searched at files/missing.txt)
 #let data = read("/files/missing.txt")
@@ -30,6 +41,25 @@
 // Error: 6-22 failed to parse csv file: found 3 instead of 2 fields in line 3
 #csv("/files/bad.csv")
 
+---
+// Test reading CSV data with a header.
+#let data = csv("/files/zoo.csv", header: true)
+#test(data.len(), 3)
+#test(data.at(0).Name, "Debby")
+#test(data.at(1).Weight, "115kg")
+
+---
+// Test decoding CSV data.
+#test(csv.decode("a,b\n1,2"), (("a", "b"), ("1", "2")))
+#test(csv.decode("a;\"b;c\"", delimiter: ";"), (("a", "b;c"),))
+#test(csv.decode("x,\"multi\nline\"\n3,4"), (("x", "multi\nline"), ("3", "4")))
+#test(csv.decode(bytes("k,v\nx,1\ny,2"), header: true), ((k: "x", v: "1"), (k: "y", v: "2")))
+#test(csv.decode("k,v", header: true), ())
+
+---
+// Error: 13-25 failed to parse csv: found 3 instead of 2 fields in line 2
+#csv.decode("a,b\n1,2,3")
+
 ---
 // Test reading JSON data.
 #let data = json("/files/zoo.json")
@@ -41,6 +71,19 @@
 // Error: 7-24 failed to parse json file: syntax error in line 3
 #json("/files/bad.json")
 
+---
+// Test decoding and encoding JSON.
+#let data = json.decode("{\"a\": [1, 2.5, null, true], \"b\": \"text\"}")
+#test(data, (a: (1, 2.5, none, true), b: "text"))
+#test(json.decode(bytes("[3, 3.0]")).map(type), ("integer", "float"))
+#test(json.encode((a: 1, b: (2.0, none)), pretty: false), "{\"a\":1,\"b\":[2.0,null]}")
+#test(json.decode(json.encode(data)), data)
+#test(json.encode("hi"), "\"hi\"")
+
+---
+// Error: 14-19 failed to parse json: syntax error in line 1
+#json.decode("{a}")
+
 ---
 // Test reading TOML data.
 #let data = toml("/files/toml-types.toml")
@@ -65,6 +108,19 @@
 // Error: 7-24 failed to parse toml file: expected `.`, `=`, index 15-16
 #toml("/files/bad.toml")
 
+---
+// Test decoding TOML data.
+#let data = toml.decode("a = 1\nb = [2.5, \"c\"]\n[d]\ne = true\nf = 2023-07-01")
+#test(data.a, 1)
+#test(data.b, (2.5, "c"))
+#test(data.d.e, true)
+#test(data.d.f, datetime(year: 2023, month: 7, day: 1))
+#test(toml.decode(bytes("x = 'y'")), (x: "y"))
+
+---
+// Error: 14-19 failed to parse toml: expected `.`, `=`, index 2-3
+#toml.decode("a b")
+
 ---
 // Test reading YAML data
 #let data = yaml("/files/yaml-types.yaml")
@@ -83,6 +139,12 @@
 // Error: 7-24 failed to parse yaml file: while parsing a flow sequence, expected ',' or ']' at line 2 column 1
 #yaml("/files/bad.yaml")
 
+---
+// Test decoding YAML data.
+#let data = yaml.decode("a: 1\nb: [2.5, c, ~]\nd:\n  e: true")
+#test(data, (a: 1, b: (2.5, "c", none), d: (e: true)))
+#test(yaml.decode(bytes("- 2023-07-01")), ("2023-07-01",))
+
 ---
 // Test reading XML data.
 #let data = xml("/files/data.xml")
@@ -111,3 +173,17 @@
 ---
 // Error: 6-22 failed to parse xml file: found closing tag 'data' instead of 'hello' in line 3
 #xml("/files/bad.xml")
+
+---
+// Test decoding XML data.
+#let data = xml.decode("<a x=\"1\">text<b/></a>")
+#test(data, ((
+  tag: "a",
+  attrs: (x: "1"),
+  children: ("text", (tag: "b", attrs: (:), children: ())),
+),))
+#test(xml.decode(bytes("<c>d</c>")).first().children, ("d",))
+
+---
+// Error: 13-22 failed to parse xml: found closing tag 'b' instead of 'a' in line 1
+#xml.decode("<a></b>")
@@ -10,6 +10,81 @@
 #test(repr(ltr), "ltr")
 #test(repr((1, 2, false, )), "(1, 2, false)")
 
+---
+// Test the representation of content.
+#test(repr[*Hey*], "strong(body: [Hey])")
+#test(repr[a *b*], "sequence([a], [ ], strong(body: [b]))")
+#test(repr(text(fill: red)[a]), "styled([a], ..)")
+#test(repr([]), "sequence()")
+
+---
+// Test comparison.
+#test(cmp(1, 2), -1)
+#test(cmp(2, 2.0), 0)
+#test(cmp("b", "a"), 1)
+#test(cmp(10pt, 1cm), -1)
+#test((3, 1, 2).sorted().map(x => cmp(x, 2)), (-1, 0, 1))
+
+---
+// Error: 5-13 cannot compare string and integer
+#cmp("a", 1)
+
+---
+// Test the `join` function.
+#test(join(), none)
+#test(join("a"), "a")
+#test(join("a", "b", none, "c"), "abc")
+#test(join(1), 1)
+#test(join([a], "b"), [a] + [b])
+#test(join((1,), (2, 3)), (1, 2, 3))
+#test(join((a: 1), (b: 2)), (a: 1, b: 2))
+
+---
+// Error: 6-14 cannot join string with integer
+#join("a", 1)
+
+---
+// Test the `catch` function.
+#test(catch(() => 1 + 2), 3)
+#test(catch(() => { return "early"; panic() }), "early")
+#let error = catch(() => 1 / 0)
+#test(error.message, "cannot divide by zero")
+#test(error.hints, ())
+#test(type(error.span.line), "integer")
+#test(error.span.column, 26)
+#test(catch(() => panic("oh no")).message, "panicked with: \"oh no\"")
+#test(catch(() => catch(() => panic()).message), "panicked")
+
+---
+// Error: 8-9 expected function, found integer
+#catch(5)
+
+---
+// Exceeded resource limits are not caught.
+// Error: 17-25 maximum function call depth exceeded
+#let f(n) = 1 + f(n + 1)
+#catch(() => f(0))
+
+---
+// Successful results can be told apart from errors by wrapping them.
+#let data = catch(() => (value: (message: "hi")))
+#test(data.value.message, "hi")
+#test("value" in catch(() => (value: json.decode("{oops}"))), false)
+
+---
+// Test the `memoize` function.
+#let fib(n) = if n <= 1 { n } else { fib(n - 1) + fib(n - 2) }
+#let fast-fib = memoize(fib)
+#test(fast-fib(40), 102334155)
+#test(fast-fib(40), 102334155)
+#test(fast-fib.name(), "fib")
+#test(memoize(calc.abs)(-2), 2)
+#test(memoize(x => x * 2)(3), 6)
+
+---
+// Error: 10-14 expected function, found string
+#memoize("no")
+
 ---
 // Test panic.
 // Error: 7-9 panicked
@@ -128,5 +203,117 @@ _No relative giraffe!_
 ```
 
 ---
-// Error: 7-12 expected semicolon or line break
+// Error: 7-12 expected semicolon or line break, found integer
 #eval("1 2")
+
+---
+// Test the eval-expr function.
+#test(eval-expr("1 + 2"), 3)
+#test(eval-expr("calc.max(x, 2)", scope: (x: 5)), 5)
+#test(eval-expr("(1, 2).map(x => x * 2)"), (2, 4))
+
+---
+// Error: 12-18 expected a single expression
+#eval-expr("1; 2")
+
+---
+// Error: 12-14 expected expression
+#eval-expr("")
+
+---
+// Error: 12-17 expected semicolon or line break, found integer
+#eval-expr("1 2")
+
+---
+// Error: 12-15 unknown variable: x
+#eval-expr("x")
+
+---
+// Test the parse-value function.
+#test(parse-value("1"), 1)
+#test(parse-value("-2.5"), -2.5)
+#test(parse-value("\"hi\""), "hi")
+#test(parse-value("(none, auto, true)"), (none, auto, true))
+#test(parse-value("(a: 1pt, \"b c\": (-3deg, 50%))"), (a: 1pt, "b c": (-3deg, 50%)))
+#test(parse-value("<intro>"), <intro>)
+
+#let data = (1, "two", (three: 3pt, four: (4em,)), -5, 6.5)
+#test(parse-value(repr(data)), data)
+
+---
+// Error: 14-25 expected literal, found function call
+#parse-value("calc.pi()")
+
+---
+// Error: 14-22 expected literal, found identifier
+#parse-value("(1, x)")
+
+---
+// Error: 14-21 expected literal, found binary expression
+#parse-value("1 + 2")
+
+---
+// Error: 14-26 expected literal, found spread
+#parse-value("(..(1, 2))")
+
+---
+// Error: 14-20 expected a single literal
+#parse-value("1; 2")
+
+---
+// Error: 14-16 expected literal
+#parse-value("")
+
+---
+// Test extracting plain text from content.
+#test(to-string([]), "")
+#test(to-string([Hello *World*!]), "Hello World!")
+#test(to-string([a \ b]), "a \n b")
+#test(to-string([#text(red)[red] and `raw`]), "red and raw")
+#test(to-string(list[A][B]), "AB")
+#test(to-string(heading[Title]), "Title")
+
+---
+// Test deterministic random numbers.
+#random.seed(42)
+#let a = (random(), random.int(0, 100), random.shuffle(range(5)))
+#random.seed(42)
+#let b = (random(), random.int(0, 100), random.shuffle(range(5)))
+#test(a, b)
+#test(a.at(2).sorted(), range(5))
+#test(random() != random(), true)
+
+---
+// Test random ranges.
+#for _ in range(100) {
+  let x = random()
+  assert(x >= 0 and x < 1)
+  let n = random.int(-3, 3)
+  assert(n >= -3 and n < 3)
+}
+#test(random.int(5, 6), 5)
+#test(random.shuffle(()), ())
+
+---
+// Test that function calls continue the sequence.
+#let roll() = random.int(0, 1000000)
+#test(roll() != roll(), true)
+#test(range(5).map(_ => random()).dedup().len(), 5)
+
+#random.seed(3)
+#let a = range(3).map(_ => random())
+#random.seed(3)
+#let b = (random(), random(), random())
+#test(a, b)
+
+---
+// Test that seeding in a function affects its caller.
+#let reset() = random.seed(5)
+#reset()
+#let a = random()
+#random.seed(5)
+#test(random(), a)
+
+---
+// Error: 2-18 range must not be empty
+#random.int(3, 3)
@@ -0,0 +1,37 @@
+// Test the testing module.
+// Ref: false
+
+---
+#let passing = testing.it("adds", () => assert(1 + 1 == 2))
+#test(passing, (description: "adds", passed: true, message: none))
+
+#let failing = testing.it("compares", () => assert.eq(1, 2))
+#test(failing.passed, false)
+#test(failing.message, "equality assertion failed: value 1 was not equal to 2")
+
+#let erroring = testing.it("divides", () => 1 / 0)
+#test(erroring.message, "cannot divide by zero")
+
+#let returning = testing.it("returns", () => { return 5; panic() })
+#test(returning.passed, true)
+
+---
+#let summary = testing.describe(
+  "Arithmetic",
+  testing.it("adds", () => assert(1 + 1 == 2)),
+  testing.it("subtracts", () => assert.eq(3 - 1, 1)),
+  testing.it("divides", () => 1 / 0),
+)
+#test(type(summary), "content")
+#test(summary.children.at(1), text(": 1 passed, 2 failed"))
+#test(summary.children.len(), 6)
+
+---
+// Error: 18-34 expected function, found string
+#testing.it("x", "not a function")
+
+---
+// Exceeded resource limits are not recorded as failures.
+// Error: 17-25 maximum function call depth exceeded
+#let f(n) = 1 + f(n + 1)
+#testing.it("recurses", () => f(0))
@@ -0,0 +1,39 @@
+// Test that explicit enumeration numbers are kept on the items.
+// Ref: false
+
+---
+#let numbers(it) = it.children.map(item => if item.has("number") { item.number })
+#show enum: it => test(numbers(it), (3, 4, none))
+3. Three
+4. Four
++ Five
+
+---
+#let numbers(it) = it.children.map(item => if item.has("number") { item.number })
+#show enum: it => test(numbers(it), (none, 10, none))
++ One
+10. Ten
++ Eleven
+
+---
+// The displayed numbers continue after the explicit ones.
+#let seen = state("seen", ())
+#set enum(numbering: n => {
+  seen.update(s => s + (n,))
+  [#n.]
+})
+3. Three
+4. Four
++ Five
+#locate(loc => test(seen.final(loc), (3, 4, 5)))
+
+---
+#let seen = state("seen", ())
+#set enum(numbering: n => {
+  seen.update(s => s + (n,))
+  [#n.]
+})
++ One
+10. Ten
++ Eleven
+#locate(loc => test(seen.final(loc), (1, 10, 11)))
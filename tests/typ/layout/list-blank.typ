@@ -0,0 +1,66 @@
+// Test how blank lines between list items are handled.
+// Ref: false
+
+---
+// A single blank line keeps the list together, but makes it wide.
+#show list: it => {
+  test(it.children.len(), 2)
+  test(it.tight, false)
+}
+
+- A
+
+- B
+
+---
+// Two blank lines end the list.
+#show list: it => {
+  test(it.children.len(), 1)
+  test(it.tight, true)
+}
+
+- A
+
+
+- B
+
+---
+// The same holds for enumerations and term lists.
+#show enum: it => test(it.children.len(), 1)
+#show terms: it => test(it.children.len(), 1)
+
++ A
+
+
+
++ B
+
+/ A: B
+
+
+/ C: D
+
+---
+// Comments and code between single blank lines don't end the list.
+#show list: it => test(it.children.len(), 3)
+
+- A
+
+// Comment
+
+- B
+
+#let x = 1
+
+- C
+
+---
+// Two blank lines still end the list after a comment.
+#show list: it => test(it.children.len(), 1)
+
+- A
+
+// Comment
+
+
+- B
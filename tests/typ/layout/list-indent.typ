@@ -0,0 +1,61 @@
+// Test list, enum, and term nesting with tab indentation.
+// Ref: false
+
+---
+// A tab indents as much as four spaces.
+#test([
+- A
+	- B
+], [
+- A
+    - B
+])
+
+#test([
++ A
+	+ B
+], [
++ A
+    + B
+])
+
+---
+// Tabs advance to the next tab stop, so mixed indentation works as
+// long as it is visually consistent.
+#test([
+  - A
+	- B
+  	- C
+], [
+  - A
+    - B
+    - C
+])
+
+---
+// A tab doesn't nest below an item that is indented by four spaces.
+#test([
+    - A
+	- B
+], [
+    - A
+    - B
+])
+
+#assert.ne([
+    - A
+	- B
+], [
+    - A
+      - B
+])
+
+---
+// Term descriptions can continue on tab-indented lines.
+#test([
+/ Term: A
+	B
+], [
+/ Term: A
+    B
+])
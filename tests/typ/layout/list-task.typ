@@ -0,0 +1,46 @@
+// Test task list items.
+// Ref: false
+
+---
+#test([- [x] Done].checked, true)
+#test([- [X] Done].checked, true)
+#test([- [ ] Open].checked, false)
+#test([- [ ] Open].body, [Open])
+#test([- [x] *Done*].body, [*Done*])
+#test([- Plain].has("checked"), false)
+
+---
+// The checkbox must be at the start of the item and followed by whitespace.
+#test([- [x]Done].has("checked"), false)
+#test([- [y] Maybe].has("checked"), false)
+#test([- [ x] Done].has("checked"), false)
+#test([- Done [x]].has("checked"), false)
+
+---
+// An item with just a checkbox is a task with an empty body.
+#test([- [x]
+].checked, true)
+#test([- [x]
+].body, [])
+
+---
+// Tasks can be mixed with regular items.
+#show list: it => {
+  test(it.children.map(item => item.at("checked", default: none)), (true, false, none))
+  it
+}
+
+- [x] Write the draft
+- [ ] Proofread it
+- Celebrate
+
+---
+#test(list.item(checked: true)[Done].checked, true)
+
+---
+// Test checkbox rendering.
+// Ref: true
+- [x] Write the draft
+- [ ] Proofread it
+  - [X] Nested and done
+- Celebrate
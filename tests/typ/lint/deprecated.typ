@@ -0,0 +1,31 @@
+// Test warnings for deprecated functions.
+// Ref: false
+
+---
+#let add(x, y) = x + y
+#let plus = add.deprecated("use `add` instead")
+
+// Warning: 7-17 `plus` is deprecated
+// Hint: 7-17 use `add` instead
+#test(plus(1, 2), 3)
+
+// The deprecation carries over to pre-applied arguments.
+#let inc = plus.with(1)
+// Warning: 7-13 `inc` is deprecated
+// Hint: 7-13 use `add` instead
+#test(inc(2), 3)
+
+// Reflection is unaffected.
+#test(plus.name(), "add")
+#test(plus.params(), add.params())
+
+---
+// Deprecated functions without a name at the call site.
+#let lib = (old: calc.abs.deprecated("use `calc.abs` instead"))
+// Warning: 7-20 function is deprecated
+// Hint: 7-20 use `calc.abs` instead
+#test((lib.old)(-1), 1)
+
+---
+// Error: 18-19 expected string, found integer
+#rect.deprecated(1)
@@ -0,0 +1,50 @@
+// Test warnings for bindings shadowed in the same block.
+// Ref: false
+
+---
+#{
+  let x = 1
+  // Warning: 7-8 shadowed variable: x
+  // Hint: 7-8 use `x = ...` to update the existing variable
+  let x = x + 1
+  test(x, 2)
+}
+
+---
+// Shadowing a binding of an outer block is fine.
+#{
+  let y = 1
+  {
+    let y = 2
+    test(y, 2)
+  }
+  test(y, 1)
+}
+
+---
+// Loop bodies get a fresh scope in each iteration.
+#for i in range(3) {
+  let v = i * 2
+  test(v, 2 * i)
+}
+
+---
+// Names starting with an underscore are not checked.
+#{
+  let _t = 1
+  let _t = 2
+  test(_t, 2)
+}
+
+---
+// Rebinding in markup is fine.
+#let z = 1
+#let z = z + 1
+#test(z, 2)
+
+---
+// A failing code block doesn't leave markup in code mode.
+// Error: 4-10 cannot add integer and string
+#{ 1 + "" }
+#let w = 1
+#let w = w + 1
@@ -0,0 +1,80 @@
+// Test warnings for unused variables.
+// Ref: false
+
+---
+#{
+  // Warning: 7-8 unused variable: x
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
+  let x = 1
+  let y = 2
+  let _z = 3
+  y
+}
+
+---
+// Destructured and function bindings.
+#let f() = {
+  // Warning: 8-9 unused variable: a
+  // Hint: 8-9 prefix the name with an underscore to silence this warning
+  let (a, b) = (1, 2)
+  // Warning: 7-8 unused variable: g
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
+  let g(x) = g(x)
+  b
+}
+
+---
+// Shadowed bindings.
+#{
+  // Warning: 7-8 unused variable: x
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
+  let x = 1
+  // Warning: 7-8 shadowed variable: x
+  // Hint: 7-8 use `x = ...` to update the existing variable
+  let x = 2
+  x
+}
+
+---
+// Reads in closures, content, math, and assignments count.
+#{
+  let a = 1
+  let b = 2
+  let c = 3
+  let d = 4
+  let f() = a
+  [#b $c$]
+  d += 1
+  f()
+}
+
+---
+// Reads in later initializers count, but imports shadow bindings.
+#{
+  let a = 1
+  let b = a + 1
+  // Warning: 7-8 unused variable: sin
+  // Hint: 7-8 prefix the name with an underscore to silence this warning
+  let sin = 2
+  import calc: sin
+  sin(b)
+}
+
+---
+// Top-level bindings are exported and thus never unused.
+#let unused = 1
+
+---
+// Reads in nested blocks count towards the innermost visible binding.
+#{
+  let a = 1
+  {
+    // Warning: 9-10 unused variable: b
+    // Hint: 9-10 prefix the name with an underscore to silence this warning
+    let b = 2
+    {
+      let a = a + 1
+      { a }
+    }
+  }
+}
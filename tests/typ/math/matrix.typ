@@ -63,3 +63,10 @@ $ mat(B, A B) $
 $ mat(B, A B, dots) $
 $ mat(B, A B, dots;) $
 $ mat(#1, #(foo: "bar")) $
+
+---
+// Test empty cells.
+// Ref: false
+#let m = $mat(1, , 3; , 5, )$
+#test(m.body.rows.map(row => row.len()), (3, 2))
+#test(m.body.rows.at(1).at(0), [])
@@ -54,3 +54,13 @@ $
 "abc" &= c \ \ \
 $
 Multiple trailing line breaks.
+
+---
+// Test that line breaks and alignment points are kept in the body.
+// Ref: false
+#let eq = $ a &= b \ c &= d $
+#let names = eq.body.children.map(child => child.func().name())
+#test(
+  names.filter(name => name in ("alignpoint", "linebreak")),
+  ("alignpoint", "linebreak", "alignpoint"),
+)
@@ -19,3 +19,10 @@ $ x = overbracket(
 $ underbracket([1, 2/3], "relevant stuff")
           arrow.l.r.double.long
   overbracket([4/5,6], "irrelevant stuff") $
+
+---
+// Test that annotations produce structured elements.
+// Ref: false
+#test($overline(x)$.body.func(), math.overline)
+#test($underbrace(x + y, "sum")$.body.annotation, [sum])
+#test($overbracket(x)$.body.has("annotation"), false)
@@ -0,0 +1,88 @@
+// Test the footnote markup syntax.
+// Ref: false
+
+---
+// The first reference produces the note, later ones refer back to it.
+Hello[^a] and again[^b] and once more[^a].
+
+[^a]: First note.
+[^b]: Second *note*.
+
+#locate(loc => {
+  let notes = query(footnote, loc)
+  test(notes.len(), 3)
+  test(notes.at(0).body, [First note.])
+  test(notes.at(1).body, [Second *note*.])
+  test(notes.at(2).body, label("^a"))
+})
+
+---
+// Definitions can come first and continue on indented lines.
+[^long]: A note
+  that spans two lines.
+
+Text[^long]
+
+#locate(loc => {
+  let notes = query(footnote, loc)
+  test(notes.len(), 1)
+  test(notes.first().body, [A note
+  that spans two lines.])
+})
+
+---
+// Footnotes can be referenced from the bodies of functions.
+#let note() = [x[^fn]]
+#note()
+
+[^fn]: From a function.
+
+#locate(loc => {
+  let notes = query(footnote, loc)
+  test(notes.len(), 1)
+  test(notes.first().body, [From a function.])
+})
+
+---
+// Repeated calls of a function refer back to the first note.
+#let note() = [x[^fn]]
+#note() and #note()
+
+[^fn]: From a function.
+
+#locate(loc => {
+  let notes = query(footnote, loc)
+  test(notes.len(), 2)
+  test(notes.first().body, [From a function.])
+  test(notes.last().body, label("^fn"))
+  test(query(label("^fn"), loc).len(), 1)
+})
+
+---
+// The definition is evaluated where it is defined.
+#let x = "outer"
+#let note(x) = [#x[^n]]
+#note("inner")
+
+[^n]: #x
+
+#locate(loc => {
+  let notes = query(footnote, loc)
+  test(notes.len(), 1)
+  test(notes.first().body, [outer])
+})
+
+---
+// Without a name or closing bracket, it's just text.
+#test([[^]].func() == footnote, false)
+#test([[^x y]].func() == footnote, false)
+
+---
+// Error: 5-12 footnote `nope` is not defined
+// Hint: 5-12 define it on its own line with `[^nope]: ...`
+Text[^nope]
+
+---
+// Error: 2:1-2:10 footnote `a` is defined multiple times
+[^a]: One
+[^a]: Two
@@ -0,0 +1,64 @@
+// Test automatic heading labels.
+// Ref: false
+
+---
+#set heading(numbering: "1.", auto-label: true)
+
+= Getting Started
+== The "Fast" Path
+= Über Größe
+
+#locate(loc => {
+  test(query(<getting-started>, loc).first().body, [Getting Started])
+  test(query(<the-fast-path>, loc).len(), 1)
+  test(query(<über-größe>, loc).len(), 1)
+})
+
+See @getting-started.
+
+---
+// Collisions get a numeric suffix.
+#set heading(auto-label: true)
+= Notes
+= Notes
+= Notes
+
+#locate(loc => {
+  let labels = query(heading, loc).map(it => it.label)
+  test(labels, (<notes>, <notes-1>, <notes-2>))
+})
+
+---
+// Explicit labels take precedence.
+#set heading(auto-label: true)
+= Intro <start>
+= Setup
+#figure([A], caption: [B]) <setup>
+
+#locate(loc => {
+  test(query(<intro>, loc), ())
+  test(query(<setup>, loc).first().func(), figure)
+})
+
+---
+// Bibliography keys take precedence.
+#set heading(auto-label: true)
+= Arrgh
+= Results
+
+#locate(loc => {
+  test(query(heading, loc).first().has("label"), false)
+  test(query(<results>, loc).len(), 1)
+})
+
+See @arrgh.
+#bibliography("/files/works.bib")
+
+---
+// Automatic labels are disabled by default.
+= Results
+
+#locate(loc => {
+  test(query(<results>, loc), ())
+  test(query(heading, loc).first().has("label"), false)
+})
@@ -0,0 +1,56 @@
+// Test that references resolve to the labelled element.
+// Ref: false
+
+// The element is only known from the second layout iteration on. The checks
+// thus run in `locate`, whose errors are only reported if they persist.
+
+---
+#set heading(numbering: "1.1")
+
+#show ref: it => {
+  let el = it.element
+  locate(_ => {
+    test(el != none, true)
+    test(el.func(), heading)
+    test(counter(heading).at(el.location()), (2, 1))
+  })
+  it
+}
+
+= Intro
+= Method
+== Setup <setup>
+See @setup.
+
+---
+#show ref: it => {
+  let el = it.element
+  locate(_ => {
+    test(el != none, true)
+    test(el.func(), figure)
+    test(el.caption, [A tiger.])
+  })
+  it
+}
+
+#figure(
+  image("/files/tiger.jpg", height: 1cm),
+  caption: [A tiger.],
+) <tiger>
+
+Look at @tiger.
+
+---
+// Labels attach to the preceding element, so a show rule can resolve
+// references to elements that aren't referenceable by default.
+#show ref: it => {
+  let el = it.element
+  locate(_ => {
+    test(el != none, true)
+    test(el.func(), strong)
+  })
+  it.supplement
+}
+
+*Important* <imp>
+@imp[Strong text]
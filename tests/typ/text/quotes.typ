@@ -60,3 +60,24 @@ He's told some books contain questionable "example text".
 "She suddenly started speaking french: #text(lang: "fr")['Je suis une banane.']" Roman told me.
 
 Some people's thought on this would be #[#set smartquote(enabled: false); "strange."]
+
+---
+// Test custom quotes.
+// Ref: false
+#set text(lang: "de")
+#set smartquote(quotes: "»«")
+"Doppelt" und 'einfach'.
+
+#set smartquote(quotes: (single: ("›", "‹")))
+"Doppelt" und 'einfach'.
+
+#set smartquote(quotes: (single: ("[", "]"), double: ("<<", ">>")))
+"Doppelt" und 'einfach'.
+
+---
+// Error: 25-30 expected 2 characters, found 3
+#set smartquote(quotes: "abc")
+
+---
+// Error: 25-39 unexpected key "triple", valid keys are "single" and "double"
+#set smartquote(quotes: (triple: "ab"))
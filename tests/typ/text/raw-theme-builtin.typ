@@ -0,0 +1,27 @@
+// Test built-in code highlighting themes.
+// Ref: false
+
+---
+#set raw(theme: "typst-dark")
+#show raw: it => {
+  test(it.theme, "typst-dark")
+  it
+}
+
+```rust
+fn main() {}
+```
+
+---
+// A named theme overrides an earlier theme file.
+#set raw(theme: "/files/halcyon.tmTheme")
+#set raw(theme: "typst-light")
+#show raw: it => test(it.theme, "typst-light")
+```typ
+#let x = 1
+```
+
+---
+// Error: 17-26 unknown theme `dracula`
+// Hint: 17-26 the built-in themes are `typst-light` and `typst-dark`
+#set raw(theme: "dracula")
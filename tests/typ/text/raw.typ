@@ -77,3 +77,63 @@ The keyword ```rust let```.
 // Unterminated.
 // Error: 1-2:1 unclosed raw text
 `endless
+
+---
+// Test language inference for untagged raw blocks.
+// Ref: false
+#set raw(infer-lang: true)
+#show raw: it => test(it.lang, "python")
+```
+#!/usr/bin/env python3
+print("Hi")
+```
+
+---
+// Test that inference is conservative.
+// Ref: false
+#set raw(infer-lang: true)
+#show raw: it => test(it.lang, none)
+```
+import this
+```
+
+---
+// Test that inference is off by default.
+// Ref: false
+#show raw: it => test(it.lang, none)
+```
+#!/bin/bash
+```
+
+---
+// Test language tag on inline raw.
+// Ref: false
+#show raw: it => {
+  test(it.lang, "rust")
+  test(it.text, "let x = 1")
+  test(it.block, false)
+}
+`rust: let x = 1`
+
+---
+// Test that ordinary inline code with colons stays untagged.
+// Ref: false
+#show raw: it => test(it.lang, none)
+`std::mem` `https://typst.app` `C:\dir` `Note: x` `Rust: x` `rust:` `rust: `
+`localhost:8080` `key:value` `color:red` `user:pass` ``` c: int```
+`h:mm:ss` `css:hover` `go:embed` `m:ss` `rust:let x = 1` `rust:  x`
+
+---
+// Test that inline code with colons keeps its full text.
+// Ref: false
+#test([`h:mm:ss`].text, "h:mm:ss")
+#test([`css:hover`].text, "css:hover")
+#test([`go:embed`].text, "go:embed")
+#test([`m:ss`].text, "m:ss")
+#test([`css: a:hover`].text, "a:hover")
+
+---
+// Test that unknown tags keep the full text.
+// Ref: false
+#show raw: it => test(it.text, "localhost:8080")
+`localhost:8080`